@@ -0,0 +1,96 @@
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Field, Ident, Index, Member};
+
+#[proc_macro_derive(Reset, attributes(reset))]
+pub fn derive_reset(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+enum FieldReset {
+	Reset,
+	Default,
+	Skip,
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+	let Data::Struct(data) = &input.data else {
+		return Err(Error::new_spanned(&input.ident, "Reset can only be derived for structs"));
+	};
+
+	let params = input.generics.type_params().map(|param| param.ident.clone()).collect::<Vec<_>>();
+	let mut statements = Vec::new();
+	let mut bounds = Vec::new();
+
+	for (index, field) in data.fields.iter().enumerate() {
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(Index::from(index)),
+		};
+
+		let ty = &field.ty;
+
+		match field_reset(field)? {
+			FieldReset::Reset => {
+				statements.push(quote!(::reusable_vec::Reset::reset(&mut self.#member);));
+				bounds.push((ty, quote!(::reusable_vec::Reset)));
+			}
+			FieldReset::Default => {
+				statements.push(quote!(self.#member = ::core::default::Default::default();));
+				bounds.push((ty, quote!(::core::default::Default)));
+			}
+			FieldReset::Skip => {}
+		}
+	}
+
+	// Like serde, only bound field types that mention a type parameter, as `Vec<K>: Reset` holds for any `K`
+	let where_clause = input.generics.make_where_clause();
+
+	for (ty, bound) in bounds {
+		if mentions_any(ty.to_token_stream(), &params) {
+			where_clause.predicates.push(parse_quote!(#ty: #bound));
+		}
+	}
+
+	let name = &input.ident;
+	let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+	Ok(quote! {
+		impl #impl_generics ::reusable_vec::Reset for #name #type_generics #where_clause {
+			#[inline]
+			fn reset(&mut self) {
+				#(#statements)*
+			}
+		}
+	})
+}
+
+fn mentions_any(tokens: TokenStream2, params: &[Ident]) -> bool {
+	tokens.into_iter().any(|token| match token {
+		TokenTree::Ident(ident) => params.contains(&ident),
+		TokenTree::Group(group) => mentions_any(group.stream(), params),
+		_ => false,
+	})
+}
+
+fn field_reset(field: &Field) -> syn::Result<FieldReset> {
+	let mut reset = FieldReset::Reset;
+
+	for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("reset")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("default") {
+				reset = FieldReset::Default;
+				Ok(())
+			} else if meta.path.is_ident("skip") {
+				reset = FieldReset::Skip;
+				Ok(())
+			} else {
+				Err(meta.error("expected `default` or `skip`"))
+			}
+		})?;
+	}
+
+	Ok(reset)
+}
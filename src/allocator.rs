@@ -0,0 +1,191 @@
+use alloc::vec::{self, Vec};
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+
+#[cfg(feature = "allocator_api")]
+pub use alloc::alloc::{Allocator, Global};
+
+use crate::ReusableVec;
+
+#[cfg(not(feature = "allocator_api"))]
+mod sealed {
+	pub trait Sealed {}
+}
+
+#[cfg(not(feature = "allocator_api"))]
+pub trait Allocator: sealed::Sealed {}
+
+#[cfg(not(feature = "allocator_api"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+#[cfg(not(feature = "allocator_api"))]
+impl sealed::Sealed for Global {}
+
+#[cfg(not(feature = "allocator_api"))]
+impl Allocator for Global {}
+
+// `Vec<T>` can't be aliased as `Buf<T, A>` on stable, so the API taking or returning it is written once here and
+// instantiated for `Vec<T, A>` or `Vec<T>`. On stable, constructors only exist for `Global` to keep `A` inferable.
+macro_rules! impl_vec_api {
+	(impl[$($from_generics:tt)*] $from_self:ty, $vec:ty, $drain:ty, $into_iter:ty $(,)?) => {
+		impl<$($from_generics)*> $from_self {
+			#[inline]
+			#[track_caller]
+			pub fn from_parts(vec: $vec, len: usize) -> Self {
+				assert!(len <= vec.len(), "len (is {len}) should be <= vec.len() (is {})", vec.len());
+				Self { len, ..Self::from(vec) }
+			}
+		}
+
+		impl<$($from_generics)*> From<$vec> for $from_self {
+			#[inline]
+			fn from(vec: $vec) -> Self {
+				Self::from_vec(vec)
+			}
+		}
+
+		impl<T, A: Allocator> ReusableVec<T, A> {
+			#[inline]
+			pub fn into_parts(self) -> ($vec, usize) {
+				(self.vec, self.len)
+			}
+
+			#[inline]
+			pub fn into_vec(mut self) -> $vec {
+				self.vec.truncate(self.len);
+				self.vec
+			}
+
+			#[inline]
+			pub fn drain_live(&mut self) -> $drain {
+				self.drain_drop(..)
+			}
+
+			#[inline]
+			pub fn drain_drop<R: RangeBounds<usize>>(&mut self, range: R) -> $drain {
+				let range = self.live_range(range);
+				self.mark_moved(range.start);
+				self.len -= range.len();
+				self.mark_changed(range.start..self.len);
+				self.vec.drain(range)
+			}
+
+			#[inline]
+			pub(crate) fn from_vec(vec: $vec) -> Self {
+				Self {
+					len: vec.len(),
+					vec,
+					allocator: PhantomData,
+					limit: None,
+					#[cfg(feature = "dirty")]
+					dirty: Vec::new(),
+					#[cfg(feature = "sanitize")]
+					sanitizer: None,
+					#[cfg(feature = "stats")]
+					stats: crate::stats::ReuseStats::default(),
+				}
+			}
+		}
+
+		impl<T, A: Allocator> From<ReusableVec<T, A>> for $vec {
+			#[inline]
+			fn from(reusable: ReusableVec<T, A>) -> $vec {
+				reusable.into_vec()
+			}
+		}
+
+		impl<T, A: Allocator> IntoIterator for ReusableVec<T, A> {
+			type Item = T;
+			type IntoIter = $into_iter;
+
+			#[inline]
+			fn into_iter(self) -> Self::IntoIter {
+				self.into_vec().into_iter()
+			}
+		}
+	};
+}
+
+#[cfg(feature = "allocator_api")]
+impl_vec_api!(impl[T, A: Allocator] ReusableVec<T, A>, Vec<T, A>, vec::Drain<'_, T, A>, vec::IntoIter<T, A>);
+
+#[cfg(not(feature = "allocator_api"))]
+impl_vec_api!(impl[T] ReusableVec<T>, Vec<T>, vec::Drain<'_, T>, vec::IntoIter<T>);
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: Allocator> ReusableVec<T, A> {
+	#[inline]
+	pub fn new_in(allocator: A) -> Self {
+		Self::from(Vec::new_in(allocator))
+	}
+
+	#[inline]
+	pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
+		Self::from(Vec::with_capacity_in(capacity, allocator))
+	}
+
+	#[inline]
+	pub fn allocator(&self) -> &A {
+		self.vec.allocator()
+	}
+
+	#[inline]
+	pub(crate) fn empty_vec(&self, capacity: usize) -> Vec<T, A>
+	where
+		A: Clone,
+	{
+		Vec::with_capacity_in(capacity, self.allocator().clone())
+	}
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T, A: Allocator> ReusableVec<T, A> {
+	#[inline]
+	pub(crate) fn empty_vec(&self, capacity: usize) -> Vec<T> {
+		Vec::with_capacity(capacity)
+	}
+}
+
+#[cfg(all(test, feature = "allocator_api"))]
+mod tests {
+	use super::*;
+	use alloc::alloc::{AllocError, Layout};
+	use core::cell::Cell;
+	use core::ptr::NonNull;
+
+	#[derive(Clone, Copy)]
+	struct Counting<'a>(&'a Cell<usize>);
+
+	unsafe impl Allocator for Counting<'_> {
+		fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+			self.0.set(self.0.get() + 1);
+			Global.allocate(layout)
+		}
+
+		unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+			// SAFETY: `ptr` was allocated by `Global` in `allocate`
+			unsafe { Global.deallocate(ptr, layout) }
+		}
+	}
+
+	#[test]
+	fn it_should_keep_the_allocator() {
+		let allocations = Cell::new(0);
+		let mut values = ReusableVec::with_capacity_in(4, Counting(&allocations));
+		values.extend([1, 2, 3]);
+		values.clear_reuse();
+		*values.push_reuse().unwrap() = 4;
+		assert_eq!(allocations.get(), 1);
+
+		let tail = values.clone().split_off_reuse(0);
+		assert_eq!(tail, [4]);
+		assert_eq!(allocations.get(), 3);
+
+		let vec: Vec<u32, _> = values.into_vec();
+		assert_eq!(vec, [4]);
+		let values = ReusableVec::from(vec);
+		assert_eq!(values.into_iter().sum::<u32>(), 4);
+		assert_eq!(allocations.get(), 3);
+	}
+}
@@ -0,0 +1,230 @@
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::{fmt, ptr, slice};
+
+use crate::Reusable;
+
+pub struct ReusableArrayVec<T, const N: usize> {
+	data: [MaybeUninit<T>; N],
+	init: usize,
+	len: usize,
+}
+
+impl<T, const N: usize> ReusableArrayVec<T, N> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { data: [const { MaybeUninit::uninit() }; N], init: 0, len: 0 }
+	}
+
+	#[inline]
+	pub const fn capacity(&self) -> usize {
+		N
+	}
+
+	#[inline]
+	pub fn push_reuse(&mut self) -> Option<&mut T> {
+		(self.len < self.init).then(move || {
+			self.len += 1;
+			// SAFETY: slots before `init` are initialized
+			unsafe { self.data[self.len - 1].assume_init_mut() }
+		})
+	}
+
+	#[inline]
+	pub fn push(&mut self, value: T) -> Result<(), T> {
+		if self.len < self.init {
+			// SAFETY: slots before `init` are initialized
+			*unsafe { self.data[self.len].assume_init_mut() } = value;
+		} else if self.init < N {
+			self.data[self.init].write(value);
+			self.init += 1;
+		} else {
+			return Err(value);
+		}
+
+		self.len += 1;
+		Ok(())
+	}
+
+	#[inline]
+	pub fn as_slice(&self) -> &[T] {
+		// SAFETY: slots before `len` are initialized, and `len <= init`
+		unsafe { slice::from_raw_parts(self.data.as_ptr().cast(), self.len) }
+	}
+
+	#[inline]
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		// SAFETY: slots before `len` are initialized, and `len <= init`
+		unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.len) }
+	}
+
+	#[inline]
+	pub fn reusable_len(&self) -> usize {
+		self.init - self.len
+	}
+
+	#[inline]
+	pub fn clear_reuse(&mut self) {
+		self.len = 0;
+	}
+
+	#[inline]
+	pub fn clear_drop(&mut self) {
+		let init = self.init;
+		self.init = 0;
+		self.len = 0;
+
+		// SAFETY: slots before `init` were initialized, and are no longer considered so
+		unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), init)) };
+	}
+
+	pub(crate) fn take_parts(&mut self, capacity: usize) -> (Vec<T>, usize) {
+		let mut vec = Vec::with_capacity(capacity.max(self.init));
+		let (init, len) = (self.init, self.len);
+		self.init = 0;
+		self.len = 0;
+
+		for slot in &self.data[..init] {
+			// SAFETY: slots before `init` were initialized, and are no longer considered so
+			vec.push(unsafe { slot.assume_init_read() });
+		}
+
+		(vec, len)
+	}
+}
+
+impl<T, const N: usize> Drop for ReusableArrayVec<T, N> {
+	#[inline]
+	fn drop(&mut self) {
+		self.clear_drop();
+	}
+}
+
+impl<T, const N: usize> Default for ReusableArrayVec<T, N> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ReusableArrayVec<T, N> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_slice(), f)
+	}
+}
+
+impl<T, const N: usize> Deref for ReusableArrayVec<T, N> {
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> DerefMut for ReusableArrayVec<T, N> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_slice()
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ReusableArrayVec<T, N> {
+	type Item = &'a T;
+	type IntoIter = <&'a [T] as IntoIterator>::IntoIter;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_slice().iter()
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ReusableArrayVec<T, N> {
+	type Item = &'a mut T;
+	type IntoIter = <&'a mut [T] as IntoIterator>::IntoIter;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T, const N: usize> Reusable for ReusableArrayVec<T, N> {
+	type Item = T;
+
+	#[inline]
+	fn push_reuse(&mut self) -> Option<&mut T> {
+		self.push_reuse()
+	}
+
+	#[inline]
+	fn try_push(&mut self, value: T) -> Result<(), T> {
+		self.push(value)
+	}
+
+	#[inline]
+	fn reusable_len(&self) -> usize {
+		self.reusable_len()
+	}
+
+	#[inline]
+	fn clear_reuse(&mut self) {
+		self.clear_reuse();
+	}
+
+	#[inline]
+	fn clear_drop(&mut self) {
+		self.clear_drop();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ReusableVec;
+	use alloc::rc::Rc;
+
+	fn fill<R: Reusable<Item = Rc<()>>>(reusable: &mut R, rc: &Rc<()>, count: usize) -> usize {
+		let mut reused = 0;
+
+		for _ in 0..count {
+			if reusable.push_reuse().is_some() {
+				reused += 1;
+			} else if reusable.try_push(rc.clone()).is_err() {
+				break;
+			}
+		}
+
+		reused
+	}
+
+	#[test]
+	fn it_should_reuse_inline_values() {
+		let rc = Rc::new(());
+		let mut array = ReusableArrayVec::<Rc<()>, 3>::new();
+		assert_eq!(fill(&mut array, &rc, 2), 0);
+		assert_eq!(array.len(), 2);
+
+		array.clear_reuse();
+		assert_eq!(fill(&mut array, &rc, 5), 2);
+		assert_eq!(array.len(), 3);
+		assert_eq!(Rc::strong_count(&rc), 4);
+		assert!(array.push(rc.clone()).is_err());
+		assert_eq!(Rc::strong_count(&rc), 4);
+
+		array.clear_drop();
+		assert_eq!(Rc::strong_count(&rc), 1);
+		assert_eq!(fill(&mut array, &rc, 1), 0);
+		array.clear_reuse();
+		drop(array);
+		assert_eq!(Rc::strong_count(&rc), 1);
+
+		let mut vec = ReusableVec::new();
+		assert_eq!(fill(&mut vec, &rc, 2), 0);
+		vec.clear_reuse();
+		assert_eq!(fill(&mut vec, &rc, 5), 2);
+		assert_eq!(vec.reusable_len(), 0);
+	}
+}
@@ -0,0 +1,162 @@
+use core::{array, fmt, iter, slice};
+
+use crate::ReusableVec;
+
+pub struct ReusableMultiBuffer<T, const N: usize> {
+	buffers: [ReusableVec<T>; N],
+	current: usize,
+}
+
+pub type ReusableDoubleBuffer<T> = ReusableMultiBuffer<T, 2>;
+
+impl<T, const N: usize> ReusableMultiBuffer<T, N> {
+	#[inline]
+	#[track_caller]
+	pub fn new() -> Self {
+		Self::from_fn(|_| ReusableVec::new())
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn from_fn<F: FnMut(usize) -> ReusableVec<T>>(create: F) -> Self {
+		assert!(N > 0, "ReusableMultiBuffer should have at least one buffer");
+		Self { buffers: array::from_fn(create), current: 0 }
+	}
+
+	#[inline]
+	pub fn advance_and_clear_reuse(&mut self) -> &mut ReusableVec<T> {
+		self.current = (self.current + 1) % N;
+		let current = &mut self.buffers[self.current];
+		current.clear_reuse();
+		current
+	}
+
+	#[inline]
+	pub fn current(&self) -> &ReusableVec<T> {
+		&self.buffers[self.current]
+	}
+
+	#[inline]
+	pub fn current_mut(&mut self) -> &mut ReusableVec<T> {
+		&mut self.buffers[self.current]
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn previous(&self) -> &ReusableVec<T> {
+		self.get(1)
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn previous_mut(&mut self) -> &mut ReusableVec<T> {
+		self.get_mut(1)
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn get(&self, age: usize) -> &ReusableVec<T> {
+		&self.buffers[self.index_of(age)]
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn get_mut(&mut self, age: usize) -> &mut ReusableVec<T> {
+		let index = self.index_of(age);
+		&mut self.buffers[index]
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn previous_and_current_mut(&mut self) -> (&ReusableVec<T>, &mut ReusableVec<T>) {
+		let previous = self.index_of(1);
+
+		if previous < self.current {
+			let (head, tail) = self.buffers.split_at_mut(self.current);
+			(&head[previous], &mut tail[0])
+		} else {
+			let (head, tail) = self.buffers.split_at_mut(previous);
+			(&tail[0], &mut head[self.current])
+		}
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn zip(&self) -> iter::Zip<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+		self.previous().iter().zip(self.current().iter())
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn zip_mut(&mut self) -> iter::Zip<slice::Iter<'_, T>, slice::IterMut<'_, T>> {
+		let (previous, current) = self.previous_and_current_mut();
+		previous.iter().zip(current.iter_mut())
+	}
+
+	#[inline]
+	pub fn buffers(&self) -> &[ReusableVec<T>; N] {
+		&self.buffers
+	}
+
+	#[inline]
+	#[track_caller]
+	fn index_of(&self, age: usize) -> usize {
+		assert!(age < N, "age (is {age}) should be < buffer count (is {N})");
+		(self.current + N - age) % N
+	}
+}
+
+impl<T> ReusableDoubleBuffer<T> {
+	#[inline]
+	pub fn swap_and_clear_reuse(&mut self) -> &mut ReusableVec<T> {
+		self.advance_and_clear_reuse()
+	}
+}
+
+impl<T, const N: usize> Default for ReusableMultiBuffer<T, N> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ReusableMultiBuffer<T, N> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_list().entries((0..N).map(|age| self.get(age))).finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn it_should_rotate_buffers() {
+		let mut positions = ReusableDoubleBuffer::<Vec<i32>>::new();
+		positions.current_mut().extend([alloc::vec![0], alloc::vec![10]]);
+
+		for _ in 0..3 {
+			let current = positions.swap_and_clear_reuse();
+			assert_eq!(current.reusable_len(), current.total_len());
+			current.push_with(Vec::clear, Vec::new);
+			current.push_with(Vec::clear, Vec::new);
+
+			for (previous, current) in positions.zip_mut() {
+				current.push(previous[0] + 1);
+			}
+		}
+
+		assert_eq!(positions.previous(), &[[2], [12]]);
+		assert_eq!(positions.current(), &[[3], [13]]);
+		assert_eq!(positions.zip().count(), 2);
+
+		let mut frames = ReusableMultiBuffer::<u32, 3>::new();
+
+		for frame in 0..4 {
+			frames.advance_and_clear_reuse().push(frame);
+		}
+
+		assert_eq!((frames.get(0)[0], frames.get(1)[0], frames.get(2)[0]), (3, 2, 1));
+	}
+}
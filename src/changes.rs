@@ -0,0 +1,224 @@
+#[cfg(feature = "dirty")]
+use core::iter::FusedIterator;
+#[cfg(feature = "dirty")]
+use core::ops::Range;
+
+use crate::allocator::Allocator;
+use crate::{ReusableVec, Slot};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Changed {
+	Unchanged,
+	Updated,
+	Inserted,
+}
+
+impl Changed {
+	#[inline]
+	pub fn is_changed(self) -> bool {
+		self != Changed::Unchanged
+	}
+}
+
+impl<T, A: Allocator> ReusableVec<T, A> {
+	#[inline]
+	pub fn push_or_update(&mut self, value: T) -> Changed
+	where
+		T: PartialEq,
+	{
+		// The reused item is compared or updated in place, so it must not be poisoned first
+		let changed = match self.push_slot_intact() {
+			Slot::Reused(reused) if *reused == value => Changed::Unchanged,
+			Slot::Reused(reused) => {
+				*reused = value;
+				Changed::Updated
+			}
+			Slot::Vacant(vacant) => {
+				vacant.insert(value);
+				Changed::Inserted
+			}
+		};
+
+		self.set_dirty(self.len - 1, changed.is_changed());
+		changed
+	}
+
+	#[inline]
+	pub fn push_or_update_with<U, C>(&mut self, update: U, create: C) -> Changed
+	where
+		U: FnOnce(&mut T) -> bool,
+		C: FnOnce() -> T,
+	{
+		let changed = match self.push_slot_intact() {
+			Slot::Reused(reused) => {
+				if update(reused) {
+					Changed::Updated
+				} else {
+					Changed::Unchanged
+				}
+			}
+			Slot::Vacant(vacant) => {
+				vacant.insert(create());
+				Changed::Inserted
+			}
+		};
+
+		self.set_dirty(self.len - 1, changed.is_changed());
+		changed
+	}
+
+	#[cfg(feature = "dirty")]
+	#[inline]
+	pub fn is_dirty(&self, index: usize) -> bool {
+		self.dirty.get(index / 64).is_some_and(|word| word & (1 << (index % 64)) != 0)
+	}
+
+	#[cfg(feature = "dirty")]
+	#[inline]
+	pub fn dirty_indices(&self) -> DirtyIndices<'_> {
+		DirtyIndices { words: &self.dirty, next_word: 0, word: 0 }
+	}
+
+	#[cfg(feature = "dirty")]
+	pub(crate) fn mark_dirty(&mut self, changed: Range<usize>) {
+		self.dirty.truncate(self.len.div_ceil(64));
+
+		if let Some(word) = self.dirty.get_mut(self.len / 64) {
+			*word &= (1 << (self.len % 64)) - 1;
+		}
+
+		for index in changed.start..changed.end.min(self.len) {
+			self.set_dirty(index, true);
+		}
+	}
+
+	#[inline]
+	#[cfg_attr(not(feature = "dirty"), allow(unused_variables))]
+	fn set_dirty(&mut self, index: usize, dirty: bool) {
+		#[cfg(feature = "dirty")]
+		{
+			let (word, bit) = (index / 64, 1 << (index % 64));
+
+			if dirty {
+				if word >= self.dirty.len() {
+					self.dirty.resize(word + 1, 0);
+				}
+
+				self.dirty[word] |= bit;
+			} else if let Some(word) = self.dirty.get_mut(word) {
+				*word &= !bit;
+			}
+		}
+	}
+}
+
+#[cfg(feature = "dirty")]
+pub struct DirtyIndices<'a> {
+	words: &'a [u64],
+	next_word: usize,
+	word: u64,
+}
+
+#[cfg(feature = "dirty")]
+impl Iterator for DirtyIndices<'_> {
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<usize> {
+		while self.word == 0 {
+			self.word = *self.words.get(self.next_word)?;
+			self.next_word += 1;
+		}
+
+		let index = (self.next_word - 1) * 64 + self.word.trailing_zeros() as usize;
+		self.word &= self.word - 1;
+		Some(index)
+	}
+}
+
+#[cfg(feature = "dirty")]
+impl FusedIterator for DirtyIndices<'_> {}
+
+#[cfg(all(test, feature = "dirty"))]
+mod tests {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn it_should_track_changed_items() {
+		let mut values = ReusableVec::new();
+
+		for value in 0..100 {
+			assert_eq!(values.push_or_update(value), Changed::Inserted);
+		}
+
+		assert_eq!(values.dirty_indices().count(), 100);
+		values.clear_reuse();
+		assert_eq!(values.dirty_indices().next(), None);
+
+		for value in 0..99 {
+			let changed = values.push_or_update(if value % 30 == 0 { value + 1 } else { value });
+			assert_eq!(changed.is_changed(), value % 30 == 0);
+		}
+
+		assert_eq!(values.dirty_indices().collect::<Vec<_>>(), [0, 30, 60, 90]);
+		assert!(values.is_dirty(30) && !values.is_dirty(31) && !values.is_dirty(99));
+
+		let changed = values.push_or_update_with(|value| core::mem::replace(value, 100) != 100, || 100);
+		assert_eq!(changed, Changed::Updated);
+		assert_eq!(values.push_or_update_with(|_| false, || 101), Changed::Inserted);
+		assert_eq!(values.dirty_indices().skip(4).collect::<Vec<_>>(), [99, 100]);
+	}
+
+	#[test]
+	fn it_should_mark_moved_items() {
+		let mut values = ReusableVec::from(alloc::vec![1, 2, 3, 4, 5, 6]);
+		values.clear_reuse();
+
+		for value in [1, 2, 3, 4, 5, 9] {
+			values.push_or_update(value);
+		}
+
+		assert_eq!(values.dirty_indices().collect::<Vec<_>>(), [5]);
+		values.pop_reuse();
+		assert_eq!(values.dirty_indices().next(), None);
+
+		assert_eq!(values.push_or_update(9), Changed::Unchanged);
+		values.remove_reuse(0);
+		assert_eq!(values.dirty_indices().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+
+		values.clear_reuse();
+		values.extend([1, 2, 3]);
+		values.swap_remove_drop(0);
+		values.insert(1, 4);
+		assert_eq!(values.dirty_indices().collect::<Vec<_>>(), [0, 1, 2]);
+		values.retain_reuse(|&value| value != 4);
+		assert_eq!(values.dirty_indices().collect::<Vec<_>>(), [0, 1]);
+		values.truncate_drop(1);
+		assert_eq!(values.dirty_indices().collect::<Vec<_>>(), [0]);
+	}
+
+	#[cfg(feature = "sanitize")]
+	#[test]
+	fn it_should_compare_unpoisoned_items() {
+		#[derive(PartialEq)]
+		struct Item(u32);
+
+		impl crate::Poison for Item {
+			fn poison(&mut self) {
+				self.0 = 0xDEAD;
+			}
+
+			fn is_poisoned(&self) -> bool {
+				self.0 == 0xDEAD
+			}
+		}
+
+		let mut items = ReusableVec::from(alloc::vec![Item(1), Item(2)]);
+		items.enable_sanitizer();
+		items.clear_reuse();
+		assert_eq!(items.push_or_update(Item(1)), Changed::Unchanged);
+		assert_eq!(items.push_or_update_with(|item| item.0 != 2, || Item(2)), Changed::Unchanged);
+		assert_eq!(items.dirty_indices().next(), None);
+	}
+}
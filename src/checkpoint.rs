@@ -0,0 +1,136 @@
+use core::mem;
+use core::ops::{Deref, DerefMut};
+
+use crate::allocator::{Allocator, Global};
+use crate::ReusableVec;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mark {
+	len: usize,
+}
+
+impl Mark {
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.len
+	}
+}
+
+impl<T, A: Allocator> ReusableVec<T, A> {
+	#[inline]
+	pub fn checkpoint(&self) -> Mark {
+		Mark { len: self.len }
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn rollback_reuse(&mut self, mark: Mark) {
+		debug_assert!(
+			mark.len <= self.len,
+			"mark (len {}) should be <= len (is {}), marks should be rolled back in reverse order",
+			mark.len,
+			self.len,
+		);
+
+		self.truncate_reuse(mark.len);
+	}
+
+	#[inline]
+	pub fn scope(&mut self) -> Scope<'_, T, A> {
+		Scope { mark: self.checkpoint(), reusable: self }
+	}
+}
+
+pub struct Scope<'a, T, A: Allocator = Global> {
+	reusable: &'a mut ReusableVec<T, A>,
+	mark: Mark,
+}
+
+impl<T, A: Allocator> Scope<'_, T, A> {
+	#[inline]
+	pub fn mark(&self) -> Mark {
+		self.mark
+	}
+
+	#[inline]
+	pub fn commit(self) {
+		mem::forget(self);
+	}
+}
+
+impl<T, A: Allocator> Deref for Scope<'_, T, A> {
+	type Target = ReusableVec<T, A>;
+
+	#[inline]
+	fn deref(&self) -> &ReusableVec<T, A> {
+		self.reusable
+	}
+}
+
+impl<T, A: Allocator> DerefMut for Scope<'_, T, A> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut ReusableVec<T, A> {
+		self.reusable
+	}
+}
+
+impl<T, A: Allocator> Drop for Scope<'_, T, A> {
+	#[inline]
+	fn drop(&mut self) {
+		self.reusable.rollback_reuse(self.mark);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::string::{String, ToString};
+
+	#[test]
+	fn it_should_roll_back_to_checkpoints() {
+		let mut nodes = ReusableVec::<String>::new();
+		nodes.push("a".to_string());
+		let mark = nodes.checkpoint();
+		nodes.push("b".to_string());
+		nodes.push("c".to_string());
+		nodes.rollback_reuse(mark);
+		assert_eq!(nodes, ["a"]);
+		assert_eq!(nodes.spare_slots(), ["b", "c"]);
+
+		{
+			let mut outer = nodes.scope();
+			outer.push_reuse().unwrap().push('!');
+
+			{
+				let mut inner = outer.scope();
+				inner.push_reuse().unwrap().push('?');
+			}
+
+			assert_eq!(*outer, ["a", "b!"]);
+			let mut inner = outer.scope();
+			inner.push("d".to_string());
+			inner.commit();
+		}
+
+		assert_eq!(nodes, ["a"]);
+		assert_eq!(nodes.spare_slots(), ["b!", "d"]);
+
+		let mut scope = nodes.scope();
+		scope.push("e".to_string());
+		scope.commit();
+		assert_eq!(nodes, ["a", "e"]);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "marks should be rolled back in reverse order"]
+	fn it_should_panic_on_out_of_order_rollback() {
+		let mut values = ReusableVec::from(alloc::vec![1]);
+		let outer = values.checkpoint();
+		values.push(2);
+		let inner = values.checkpoint();
+		values.push(3);
+		values.rollback_reuse(outer);
+		values.rollback_reuse(inner);
+	}
+}
@@ -0,0 +1,295 @@
+use alloc::collections::vec_deque::{self, VecDeque};
+use core::fmt;
+use core::ops::{Index, IndexMut};
+
+pub struct ReusableVecDeque<T> {
+	deque: VecDeque<T>,
+	len: usize,
+}
+
+impl<T> ReusableVecDeque<T> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { deque: VecDeque::new(), len: 0 }
+	}
+
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { deque: VecDeque::with_capacity(capacity), len: 0 }
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	#[inline]
+	pub fn reusable_len(&self) -> usize {
+		self.deque.len() - self.len
+	}
+
+	#[inline]
+	pub fn push_back_reuse(&mut self) -> Option<&mut T> {
+		(self.len < self.deque.len()).then(move || {
+			self.len += 1;
+			&mut self.deque[self.len - 1]
+		})
+	}
+
+	#[inline]
+	pub fn push_front_reuse(&mut self) -> Option<&mut T> {
+		let spare = self.pop_spare()?;
+		self.deque.push_front(spare);
+		self.len += 1;
+		self.deque.front_mut()
+	}
+
+	#[inline]
+	pub fn push_back(&mut self, value: T) {
+		self.len = self.len.checked_add(1).unwrap();
+
+		if self.len <= self.deque.len() {
+			self.deque[self.len - 1] = value;
+		} else {
+			self.deque.push_back(value);
+		}
+	}
+
+	#[inline]
+	pub fn push_front(&mut self, value: T) {
+		self.len = self.len.checked_add(1).unwrap();
+		self.pop_spare();
+		self.deque.push_front(value);
+	}
+
+	#[inline]
+	pub fn pop_back_reuse(&mut self) -> Option<&mut T> {
+		(self.len > 0).then(move || {
+			self.len -= 1;
+			&mut self.deque[self.len]
+		})
+	}
+
+	#[inline]
+	pub fn pop_front_reuse(&mut self) -> Option<&mut T> {
+		if self.len == 0 {
+			return None;
+		}
+
+		let front = self.deque.pop_front().unwrap();
+		self.deque.push_back(front);
+		self.len -= 1;
+		self.deque.back_mut()
+	}
+
+	#[inline]
+	pub fn pop_back_drop(&mut self) -> Option<T> {
+		(self.len > 0).then(|| {
+			self.len -= 1;
+			self.deque.swap_remove_back(self.len).unwrap()
+		})
+	}
+
+	#[inline]
+	pub fn pop_front_drop(&mut self) -> Option<T> {
+		(self.len > 0).then(|| {
+			self.len -= 1;
+			self.deque.pop_front().unwrap()
+		})
+	}
+
+	#[inline]
+	pub fn front(&self) -> Option<&T> {
+		self.get(0)
+	}
+
+	#[inline]
+	pub fn front_mut(&mut self) -> Option<&mut T> {
+		self.get_mut(0)
+	}
+
+	#[inline]
+	pub fn back(&self) -> Option<&T> {
+		self.get(self.len.checked_sub(1)?)
+	}
+
+	#[inline]
+	pub fn back_mut(&mut self) -> Option<&mut T> {
+		self.get_mut(self.len.checked_sub(1)?)
+	}
+
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<&T> {
+		(index < self.len).then(|| &self.deque[index])
+	}
+
+	#[inline]
+	pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		(index < self.len).then(move || &mut self.deque[index])
+	}
+
+	#[inline]
+	pub fn iter(&self) -> vec_deque::Iter<'_, T> {
+		self.deque.range(..self.len)
+	}
+
+	#[inline]
+	pub fn iter_mut(&mut self) -> vec_deque::IterMut<'_, T> {
+		self.deque.range_mut(..self.len)
+	}
+
+	#[inline]
+	pub fn as_slices(&self) -> (&[T], &[T]) {
+		let (front, back) = self.deque.as_slices();
+
+		if self.len <= front.len() {
+			(&front[..self.len], &[])
+		} else {
+			(front, &back[..self.len - front.len()])
+		}
+	}
+
+	#[inline]
+	pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+		let (front, back) = self.deque.as_mut_slices();
+
+		if self.len <= front.len() {
+			(&mut front[..self.len], &mut [])
+		} else {
+			let back_len = self.len - front.len();
+			(front, &mut back[..back_len])
+		}
+	}
+
+	#[inline]
+	pub fn clear_reuse(&mut self) {
+		self.len = 0;
+	}
+
+	#[inline]
+	pub fn clear_drop(&mut self) {
+		self.deque.clear();
+		self.len = 0;
+	}
+
+	#[inline]
+	fn pop_spare(&mut self) -> Option<T> {
+		if self.len < self.deque.len() {
+			self.deque.pop_back()
+		} else {
+			None
+		}
+	}
+}
+
+impl<T> Default for ReusableVecDeque<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for ReusableVecDeque<T> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<T> From<VecDeque<T>> for ReusableVecDeque<T> {
+	#[inline]
+	fn from(deque: VecDeque<T>) -> Self {
+		Self { len: deque.len(), deque }
+	}
+}
+
+impl<T> From<ReusableVecDeque<T>> for VecDeque<T> {
+	#[inline]
+	fn from(mut reusable: ReusableVecDeque<T>) -> VecDeque<T> {
+		reusable.deque.truncate(reusable.len);
+		reusable.deque
+	}
+}
+
+impl<T> Index<usize> for ReusableVecDeque<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, index: usize) -> &T {
+		self.get(index).expect("out of bounds access")
+	}
+}
+
+impl<T> IndexMut<usize> for ReusableVecDeque<T> {
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut T {
+		self.get_mut(index).expect("out of bounds access")
+	}
+}
+
+impl<'a, T> IntoIterator for &'a ReusableVecDeque<T> {
+	type Item = &'a T;
+	type IntoIter = vec_deque::Iter<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a mut ReusableVecDeque<T> {
+	type Item = &'a mut T;
+	type IntoIter = vec_deque::IterMut<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn it_should_reuse_values_popped_from_both_ends() {
+		let mut queue = ReusableVecDeque::<Vec<u32>>::new();
+
+		for i in 0..4 {
+			queue.push_back(Vec::with_capacity(10));
+			queue.back_mut().unwrap().push(i);
+		}
+
+		assert_eq!(*queue.pop_front_reuse().unwrap(), [0]);
+		assert_eq!(*queue.pop_back_reuse().unwrap(), [3]);
+		assert_eq!(queue.len(), 2);
+		assert_eq!(queue.reusable_len(), 2);
+
+		let front = queue.push_front_reuse().unwrap();
+		assert_eq!(front.capacity(), 10);
+		front.clear();
+		let back = queue.push_back_reuse().unwrap();
+		back.clear();
+		back.push(4);
+		assert!(queue.push_back_reuse().is_none());
+		assert_eq!(queue.iter().cloned().collect::<Vec<_>>(), [vec![], vec![1], vec![2], vec![4]]);
+
+		assert_eq!(queue.pop_front_drop(), Some(vec![]));
+		assert_eq!(queue.pop_back_drop(), Some(vec![4]));
+		queue.push_front(vec![5]);
+		assert_eq!(queue.as_slices().0.len() + queue.as_slices().1.len(), 3);
+		assert_eq!(queue[0], [5]);
+
+		queue.clear_reuse();
+		assert_eq!(queue.reusable_len(), 3);
+		queue.clear_drop();
+		assert!(queue.push_front_reuse().is_none());
+	}
+}
@@ -0,0 +1,270 @@
+use alloc::vec::Vec;
+use core::iter::{Flatten, FusedIterator};
+use core::slice;
+
+use crate::ReusableVec;
+
+pub struct ReusableJagged<T> {
+	rows: ReusableVec<ReusableVec<T>>,
+}
+
+impl<T> ReusableJagged<T> {
+	#[inline]
+	pub fn new() -> Self {
+		Self { rows: ReusableVec::new() }
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.rows.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.rows.is_empty()
+	}
+
+	#[inline]
+	pub fn push_row(&mut self) -> &mut ReusableVec<T> {
+		self.rows.push_with(ReusableVec::clear_reuse, ReusableVec::new)
+	}
+
+	#[inline]
+	pub fn row(&self, index: usize) -> Option<&[T]> {
+		self.rows.get(index).map(ReusableVec::as_slice)
+	}
+
+	#[inline]
+	pub fn row_mut(&mut self, index: usize) -> Option<&mut ReusableVec<T>> {
+		self.rows.get_mut(index)
+	}
+
+	#[inline]
+	pub fn rows(&self) -> &[ReusableVec<T>] {
+		self.rows.as_slice()
+	}
+
+	#[inline]
+	pub fn rows_mut(&mut self) -> &mut [ReusableVec<T>] {
+		self.rows.as_mut_slice()
+	}
+
+	#[inline]
+	pub fn iter_flat(&self) -> Flatten<slice::Iter<'_, ReusableVec<T>>> {
+		self.rows.iter().flatten()
+	}
+
+	#[inline]
+	pub fn iter_flat_mut(&mut self) -> Flatten<slice::IterMut<'_, ReusableVec<T>>> {
+		self.rows.iter_mut().flatten()
+	}
+
+	#[inline]
+	pub fn clear_reuse(&mut self) {
+		self.rows.clear_reuse();
+	}
+
+	#[inline]
+	pub fn clear_drop(&mut self) {
+		self.rows.clear_drop();
+	}
+}
+
+impl<T> Default for ReusableJagged<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+pub struct ReusableFlatJagged<T> {
+	values: ReusableVec<T>,
+	ends: Vec<usize>,
+}
+
+impl<T> ReusableFlatJagged<T> {
+	#[inline]
+	pub fn new() -> Self {
+		Self { values: ReusableVec::new(), ends: Vec::new() }
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.ends.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.ends.is_empty()
+	}
+
+	#[inline]
+	pub fn push_row(&mut self) -> FlatRow<'_, T> {
+		let start = self.values.len();
+		self.ends.push(start);
+		FlatRow { values: &mut self.values, end: self.ends.last_mut().unwrap(), start }
+	}
+
+	#[inline]
+	pub fn row(&self, index: usize) -> Option<&[T]> {
+		let end = *self.ends.get(index)?;
+		let start = index.checked_sub(1).map_or(0, |previous| self.ends[previous]);
+		Some(&self.values[start..end])
+	}
+
+	#[inline]
+	pub fn row_mut(&mut self, index: usize) -> Option<&mut [T]> {
+		let end = *self.ends.get(index)?;
+		let start = index.checked_sub(1).map_or(0, |previous| self.ends[previous]);
+		Some(&mut self.values[start..end])
+	}
+
+	#[inline]
+	pub fn rows(&self) -> Rows<'_, T> {
+		Rows { values: &self.values, ends: self.ends.iter(), start: 0 }
+	}
+
+	#[inline]
+	pub fn flat(&self) -> &[T] {
+		self.values.as_slice()
+	}
+
+	#[inline]
+	pub fn flat_mut(&mut self) -> &mut [T] {
+		self.values.as_mut_slice()
+	}
+
+	#[inline]
+	pub fn offsets(&self) -> &[usize] {
+		&self.ends
+	}
+
+	#[inline]
+	pub fn clear_reuse(&mut self) {
+		self.values.clear_reuse();
+		self.ends.clear();
+	}
+
+	#[inline]
+	pub fn clear_drop(&mut self) {
+		self.values.clear_drop();
+		self.ends.clear();
+	}
+}
+
+impl<T> Default for ReusableFlatJagged<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+pub struct FlatRow<'a, T> {
+	values: &'a mut ReusableVec<T>,
+	end: &'a mut usize,
+	start: usize,
+}
+
+impl<T> FlatRow<'_, T> {
+	#[inline]
+	pub fn push_reuse(&mut self) -> Option<&mut T> {
+		let reused = self.values.push_reuse()?;
+		*self.end += 1;
+		Some(reused)
+	}
+
+	#[inline]
+	pub fn push(&mut self, value: T) {
+		self.values.push(value);
+		*self.end += 1;
+	}
+
+	#[inline]
+	pub fn push_with<R, C>(&mut self, reset: R, create: C) -> &mut T
+	where
+		R: FnOnce(&mut T),
+		C: FnOnce() -> T,
+	{
+		let pushed = self.values.push_with(reset, create);
+		*self.end += 1;
+		pushed
+	}
+
+	#[inline]
+	pub fn as_slice(&self) -> &[T] {
+		&self.values[self.start..]
+	}
+
+	#[inline]
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		&mut self.values[self.start..]
+	}
+}
+
+pub struct Rows<'a, T> {
+	values: &'a [T],
+	ends: slice::Iter<'a, usize>,
+	start: usize,
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+	type Item = &'a [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<&'a [T]> {
+		let end = *self.ends.next()?;
+		let row = &self.values[self.start..end];
+		self.start = end;
+		Some(row)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.ends.size_hint()
+	}
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+
+impl<T> FusedIterator for Rows<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn it_should_reuse_rows() {
+		let mut jagged = ReusableJagged::<Vec<u32>>::new();
+		let mut flat = ReusableFlatJagged::<Vec<u32>>::new();
+
+		for frame in 0..2 {
+			for len in [2, 0, 1] {
+				let row = jagged.push_row();
+				assert!(row.is_empty());
+
+				let mut flat_row = flat.push_row();
+
+				for i in 0..len {
+					row.push_with(Vec::clear, Vec::new).push(frame * 10 + i);
+					flat_row.push_with(Vec::clear, Vec::new).push(frame * 10 + i);
+				}
+
+				assert_eq!(flat_row.as_slice(), row.as_slice());
+			}
+
+			assert_eq!(jagged.len(), 3);
+			assert_eq!(jagged.row(1), Some(&[][..]));
+			let values: Vec<u32> = jagged.iter_flat().flatten().copied().collect();
+			assert_eq!(values, [frame * 10, frame * 10 + 1, frame * 10]);
+			assert!(flat.rows().eq(jagged.rows().iter().map(|row| row.as_slice())));
+			assert_eq!(flat.row(2), jagged.row(2));
+			assert_eq!(flat.offsets(), [2, 2, 3]);
+
+			jagged.clear_reuse();
+			flat.clear_reuse();
+		}
+
+		assert_eq!(jagged.push_row().reusable_len(), 2);
+		assert!(flat.push_row().push_reuse().unwrap().capacity() > 0);
+	}
+}
@@ -0,0 +1,103 @@
+use crate::allocator::Allocator;
+use crate::ReusableVec;
+
+pub trait Keyed {
+	type Key: PartialEq + ?Sized;
+
+	fn key(&self) -> &Self::Key;
+}
+
+impl<T: Keyed, A: Allocator> ReusableVec<T, A> {
+	pub fn push_keyed_reuse(&mut self, key: &T::Key) -> Option<&mut T> {
+		// An item with a matching key is returned intact to keep its state, so only the fallback one is poisoned
+		if let Some(offset) = self.spare_slots().iter().position(|value| value.key() == key) {
+			self.vec.swap(self.len, self.len + offset);
+			return self.push_reuse_intact();
+		}
+
+		// Falling back to the last spare item leaves the following ones for their keys when items keep their order
+		if self.len < self.vec.len() {
+			let last = self.vec.len() - 1;
+			self.vec.swap(self.len, last);
+		}
+
+		self.push_reuse()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::string::{String, ToString};
+
+	struct Item {
+		key: u32,
+		cache: String,
+	}
+
+	impl Keyed for Item {
+		type Key = u32;
+
+		fn key(&self) -> &u32 {
+			&self.key
+		}
+	}
+
+	#[cfg(feature = "sanitize")]
+	impl crate::Poison for Item {
+		fn poison(&mut self) {
+			self.cache.clear();
+		}
+
+		fn is_poisoned(&self) -> bool {
+			self.cache.is_empty()
+		}
+	}
+
+	#[test]
+	fn it_should_reuse_items_with_matching_keys() {
+		let mut items = ReusableVec::new();
+
+		for key in [1, 2, 3] {
+			items.push(Item { key, cache: key.to_string() });
+		}
+
+		items.clear_reuse();
+
+		for key in [3, 1, 4] {
+			let item = items.push_keyed_reuse(&key).unwrap();
+
+			if item.key != key {
+				item.key = key;
+				item.cache = key.to_string();
+			}
+		}
+
+		assert!(items.iter().map(|item| (item.key, item.cache.as_str())).eq([(3, "3"), (1, "1"), (4, "4")]));
+		assert!(items.push_keyed_reuse(&5).is_none());
+	}
+
+	#[cfg(feature = "sanitize")]
+	#[test]
+	fn it_should_only_poison_items_with_other_keys() {
+		let mut items = ReusableVec::new();
+		items.enable_sanitizer();
+		items.push(Item { key: 1, cache: "1".to_string() });
+		items.push(Item { key: 2, cache: "2".to_string() });
+		items.clear_reuse();
+
+		assert_eq!(items.push_keyed_reuse(&2).unwrap().cache, "2");
+		assert_eq!(items.push_keyed_reuse(&3).unwrap().cache, "");
+	}
+
+	#[cfg(feature = "stats")]
+	#[test]
+	fn it_should_count_keyed_reuse() {
+		let mut items = ReusableVec::new();
+		items.push(Item { key: 1, cache: String::new() });
+		items.clear_reuse();
+		assert!(items.push_keyed_reuse(&2).is_some());
+		assert!(items.push_keyed_reuse(&1).is_none());
+		assert_eq!((items.stats().reuse_hits, items.stats().reuse_misses), (1, 1));
+	}
+}
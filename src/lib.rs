@@ -1,25 +1,176 @@
-pub struct ReusableVec<T> {
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
+
+#[cfg(all(test, feature = "derive"))]
+extern crate self as reusable_vec;
+
+mod allocator;
+mod array;
+mod buffer;
+mod changes;
+mod checkpoint;
+mod deque;
+pub mod jagged;
+mod keyed;
+mod limit;
+#[cfg(feature = "rayon")]
+mod rayon_impls;
+#[cfg(feature = "std")]
+pub mod pool;
+mod reset;
+#[cfg(feature = "sanitize")]
+mod sanitize;
+pub mod slab;
+mod small;
+#[cfg(feature = "stats")]
+mod stats;
+pub mod strings;
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+pub use allocator::{Allocator, Global};
+pub use array::ReusableArrayVec;
+pub use buffer::{ReusableDoubleBuffer, ReusableMultiBuffer};
+pub use changes::Changed;
+#[cfg(feature = "dirty")]
+pub use changes::DirtyIndices;
+pub use checkpoint::{Mark, Scope};
+pub use deque::ReusableVecDeque;
+pub use jagged::{ReusableFlatJagged, ReusableJagged};
+pub use keyed::Keyed;
+pub use limit::{HeapSize, ReuseLimit};
+pub use reset::Reset;
+#[cfg(feature = "sanitize")]
+pub use sanitize::Poison;
+pub use slab::{Handle, ReusableSlab};
+pub use small::ReusableSmallVec;
+#[cfg(feature = "stats")]
+pub use stats::ReuseStats;
+pub use strings::{ReusableString, ReusableStringVec};
+#[cfg(feature = "derive")]
+pub use reusable_vec_derive::Reset;
+
+pub trait Reusable: DerefMut<Target = [Self::Item]> {
+	type Item;
+
+	fn push_reuse(&mut self) -> Option<&mut Self::Item>;
+	fn try_push(&mut self, value: Self::Item) -> Result<(), Self::Item>;
+	fn reusable_len(&self) -> usize;
+	fn clear_reuse(&mut self);
+	fn clear_drop(&mut self);
+}
+
+pub struct ReusableVec<T, A: Allocator = Global> {
+	#[cfg(feature = "allocator_api")]
+	vec: Vec<T, A>,
+	#[cfg(not(feature = "allocator_api"))]
 	vec: Vec<T>,
+	allocator: PhantomData<A>,
 	len: usize,
+	limit: Option<Box<ReuseLimit<T>>>,
+	#[cfg(feature = "dirty")]
+	dirty: Vec<u64>,
+	#[cfg(feature = "sanitize")]
+	sanitizer: Option<sanitize::Sanitizer<T>>,
+	#[cfg(feature = "stats")]
+	stats: stats::ReuseStats,
 }
 
 impl<T> ReusableVec<T> {
 	#[inline]
 	pub fn new() -> Self {
-		Self { vec: Vec::new(), len: 0 }
+		Self::from(Vec::new())
 	}
 
 	#[inline]
 	pub fn with_capacity(capacity: usize) -> Self {
-		Self { vec: Vec::with_capacity(capacity), len: 0 }
+		Self::from(Vec::with_capacity(capacity))
 	}
+}
 
+impl<T, A: Allocator> ReusableVec<T, A> {
 	#[inline]
 	pub fn push_reuse(&mut self) -> Option<&mut T> {
-		(self.len < self.vec.len()).then(move || {
+		if self.len < self.vec.len() {
+			self.mark_reused(self.len..self.len + 1);
+		}
+
+		self.push_reuse_intact()
+	}
+
+	#[inline]
+	pub fn push_slot(&mut self) -> Slot<'_, T, A> {
+		if self.len < self.vec.len() {
+			self.mark_reused(self.len..self.len + 1);
+		}
+
+		self.push_slot_intact()
+	}
+
+	#[inline]
+	fn push_slot_intact(&mut self) -> Slot<'_, T, A> {
+		self.record_reuse(self.len < self.vec.len());
+
+		if self.len < self.vec.len() {
 			self.len += 1;
-			&mut self.vec[self.len - 1]
-		})
+			Slot::Reused(&mut self.vec[self.len - 1])
+		} else {
+			Slot::Vacant(VacantSlot { reusable: self })
+		}
+	}
+
+	#[inline]
+	pub fn push_with<R, C>(&mut self, reset: R, create: C) -> &mut T
+	where
+		R: FnOnce(&mut T),
+		C: FnOnce() -> T,
+	{
+		match self.push_slot() {
+			Slot::Reused(reused) => {
+				reset(reused);
+				reused
+			}
+			Slot::Vacant(vacant) => vacant.insert(create()),
+		}
+	}
+
+	pub fn extend_reuse<I, R, C>(&mut self, iter: I, mut reuse: R, mut create: C)
+	where
+		I: IntoIterator,
+		R: FnMut(&mut T, I::Item),
+		C: FnMut(I::Item) -> T,
+	{
+		let iter = iter.into_iter();
+		self.vec.reserve(iter.size_hint().0.saturating_sub(self.reusable_len()));
+
+		for item in iter {
+			match self.push_slot() {
+				Slot::Reused(reused) => reuse(reused, item),
+				Slot::Vacant(vacant) => {
+					vacant.insert(create(item));
+				}
+			}
+		}
+	}
+
+	#[inline]
+	pub fn push_reset(&mut self) -> &mut T
+	where
+		T: Reset + Default,
+	{
+		self.push_with(T::reset, T::default)
 	}
 
 	#[inline]
@@ -30,88 +181,603 @@ impl<T> ReusableVec<T> {
 			self.vec[self.len - 1] = value;
 		} else {
 			self.vec.push(value);
+			self.record_growth(1);
 		}
 	}
 
 	#[inline]
 	pub fn as_slice(&self) -> &[T] {
+		self.check_reinitialized();
 		&self.vec[..self.len]
 	}
 
 	#[inline]
 	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		self.mark_moved(0);
 		&mut self.vec[..self.len]
 	}
 
 	#[inline]
-	pub fn into_vec(mut self) -> Vec<T> {
-		self.vec.truncate(self.len);
-		self.vec
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	#[inline]
+	pub fn reusable_len(&self) -> usize {
+		self.vec.len() - self.len
+	}
+
+	#[inline]
+	pub fn total_len(&self) -> usize {
+		self.vec.len()
+	}
+
+	#[inline]
+	pub fn spare_slots(&self) -> &[T] {
+		&self.vec[self.len..]
+	}
+
+	#[inline]
+	pub fn spare_slots_mut(&mut self) -> &mut [T] {
+		&mut self.vec[self.len..]
+	}
+
+	#[inline]
+	pub fn pop_spare(&mut self) -> Option<T> {
+		if self.len < self.vec.len() {
+			self.vec.pop()
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	pub fn push_spare(&mut self, value: T) {
+		self.vec.push(value);
+		self.record_growth(0);
+		self.enforce_reuse_limit();
+	}
+
+	#[inline]
+	pub fn shrink_reusable_to(&mut self, reusable_len: usize) {
+		self.vec.truncate(self.len.saturating_add(reusable_len));
+	}
+
+	pub fn reserve_reusable<F: FnMut() -> T>(&mut self, reusable_len: usize, create: F) {
+		let total_len = self.len.checked_add(reusable_len).unwrap();
+
+		if total_len > self.vec.len() {
+			self.vec.resize_with(total_len, create);
+			self.record_growth(0);
+		}
 	}
 
 	#[inline]
 	pub fn clear_reuse(&mut self) {
 		self.len = 0;
+		self.mark_changed(0..0);
+		self.record_clear(true);
+		self.enforce_reuse_limit();
 	}
 
 	#[inline]
 	pub fn clear_drop(&mut self) {
 		self.vec.clear();
 		self.len = 0;
+		self.mark_changed(0..0);
+		self.record_clear(false);
+	}
+
+	#[inline]
+	pub fn pop_reuse(&mut self) -> Option<&mut T> {
+		(self.len > 0).then(move || {
+			self.len -= 1;
+			self.mark_changed(self.len..self.len);
+			&mut self.vec[self.len]
+		})
+	}
+
+	#[inline]
+	pub fn pop_drop(&mut self) -> Option<T> {
+		(self.len > 0).then(|| {
+			self.len -= 1;
+			self.mark_changed(self.len..self.len);
+			self.vec.swap_remove(self.len)
+		})
+	}
+
+	#[inline]
+	pub fn truncate_reuse(&mut self, len: usize) {
+		self.len = self.len.min(len);
+		self.mark_changed(self.len..self.len);
+		self.enforce_reuse_limit();
+	}
+
+	#[inline]
+	pub fn truncate_drop(&mut self, len: usize) {
+		if len < self.len {
+			self.vec.drain(len..self.len);
+			self.len = len;
+			self.mark_changed(len..len);
+		}
+	}
+
+	#[inline]
+	pub fn insert_reuse(&mut self, index: usize) -> Option<&mut T> {
+		self.assert_insert_index(index);
+		self.record_reuse(self.len < self.vec.len());
+
+		(self.len < self.vec.len()).then(move || {
+			self.vec[index..=self.len].rotate_right(1);
+			self.len += 1;
+			self.mark_reused(index..index + 1);
+			self.mark_changed(index..self.len);
+			&mut self.vec[index]
+		})
+	}
+
+	#[inline]
+	pub fn insert(&mut self, index: usize, value: T) {
+		self.assert_insert_index(index);
+
+		if self.len < self.vec.len() {
+			self.vec[self.len] = value;
+			self.vec[index..=self.len].rotate_right(1);
+		} else {
+			self.vec.insert(index, value);
+			self.record_growth(1);
+		}
+
+		self.len += 1;
+		self.mark_changed(index..self.len);
+	}
+
+	#[inline]
+	pub fn remove_reuse(&mut self, index: usize) -> &mut T {
+		self.assert_index(index);
+		self.mark_moved(index);
+		self.vec[index..self.len].rotate_left(1);
+		self.len -= 1;
+		self.mark_changed(index..self.len);
+		&mut self.vec[self.len]
+	}
+
+	#[inline]
+	pub fn remove_drop(&mut self, index: usize) -> T {
+		self.assert_index(index);
+		self.mark_moved(index);
+		self.len -= 1;
+		self.mark_changed(index..self.len);
+		self.vec.remove(index)
+	}
+
+	#[inline]
+	pub fn swap_remove_reuse(&mut self, index: usize) -> &mut T {
+		self.assert_index(index);
+		self.mark_moved(index);
+		self.vec.swap(index, self.len - 1);
+		self.len -= 1;
+		self.mark_changed(index..index + 1);
+		&mut self.vec[self.len]
+	}
+
+	#[inline]
+	pub fn swap_remove_drop(&mut self, index: usize) -> T {
+		self.assert_index(index);
+		self.mark_moved(index);
+		self.vec.swap(index, self.len - 1);
+		self.len -= 1;
+		self.mark_changed(index..index + 1);
+		self.vec.swap_remove(self.len)
+	}
+
+	pub fn retain_reuse<F: FnMut(&T) -> bool>(&mut self, f: F) {
+		self.retain_live(f);
+		self.enforce_reuse_limit();
+	}
+
+	pub fn retain_drop<F: FnMut(&T) -> bool>(&mut self, f: F) {
+		let len = self.len;
+		self.retain_live(f);
+		self.vec.drain(self.len..len);
+	}
+
+	#[inline]
+	pub fn drain_reuse<R: RangeBounds<usize>>(&mut self, range: R) -> &mut [T] {
+		let range = self.live_range(range);
+		let count = range.len();
+		self.mark_moved(range.start);
+		self.vec[range.start..self.len].rotate_left(count);
+		self.len -= count;
+		self.mark_changed(range.start..self.len);
+		self.enforce_reuse_limit();
+		let end = self.vec.len().min(self.len + count);
+		&mut self.vec[self.len..end]
+	}
+
+	#[inline]
+	pub fn split_off_reuse(&mut self, at: usize) -> Self
+	where
+		A: Clone,
+	{
+		self.assert_insert_index(at);
+		let mut tail = self.empty_vec(self.len - at);
+		tail.extend(self.vec.drain(at..self.len));
+		self.len = at;
+		self.mark_changed(at..at);
+		self.with_settings_of(Self::from_vec(tail))
+	}
+
+	#[inline]
+	pub fn split_off_drop(&mut self, at: usize) -> Self
+	where
+		A: Clone,
+	{
+		self.split_off_reuse(at)
+	}
+
+	#[inline]
+	pub fn reuse_limit(&self) -> ReuseLimit<T> {
+		self.limit.as_deref().copied().unwrap_or_default()
+	}
+
+	#[inline]
+	pub fn set_reuse_limit(&mut self, limit: ReuseLimit<T>) {
+		self.limit = (!limit.is_unlimited()).then(|| Box::new(limit));
+		self.enforce_reuse_limit();
+	}
+
+	#[inline]
+	fn with_settings_of(&self, reusable: Self) -> Self {
+		Self {
+			limit: self.limit.clone(),
+			#[cfg(feature = "sanitize")]
+			sanitizer: self.sanitizer.clone(),
+			..reusable
+		}
+	}
+
+	#[inline]
+	fn push_reuse_intact(&mut self) -> Option<&mut T> {
+		self.record_reuse(self.len < self.vec.len());
+
+		(self.len < self.vec.len()).then(move || {
+			self.len += 1;
+			&mut self.vec[self.len - 1]
+		})
+	}
+
+	#[inline]
+	#[cfg_attr(not(feature = "sanitize"), allow(unused_variables))]
+	fn mark_reused(&mut self, reused: Range<usize>) {
+		#[cfg(feature = "sanitize")]
+		if let Some(sanitizer) = &self.sanitizer {
+			sanitizer.mark_reused(reused.start);
+			sanitizer.poison(&mut self.vec[reused]);
+		}
+	}
+
+	#[inline]
+	#[cfg_attr(not(feature = "sanitize"), allow(unused_variables))]
+	fn mark_moved(&self, index: usize) {
+		#[cfg(feature = "sanitize")]
+		if let Some(sanitizer) = &self.sanitizer {
+			sanitizer.mark_moved(index);
+		}
+	}
+
+	#[inline]
+	fn check_reinitialized(&self) {
+		#[cfg(all(feature = "sanitize", debug_assertions))]
+		if let Some(sanitizer) = &self.sanitizer {
+			sanitizer.check(&self.vec[..self.len]);
+		}
+	}
+
+	#[inline]
+	fn record_reuse(&mut self, hit: bool) {
+		self.record_reuses(usize::from(hit), usize::from(!hit));
+	}
+
+	#[inline]
+	#[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+	fn record_reuses(&mut self, hits: usize, misses: usize) {
+		#[cfg(feature = "stats")]
+		{
+			self.stats.reuse_hits += hits as u64;
+			self.stats.reuse_misses += misses as u64;
+		}
+	}
+
+	#[inline]
+	#[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+	fn record_growth(&mut self, fresh_pushes: usize) {
+		#[cfg(feature = "stats")]
+		{
+			self.stats.fresh_pushes += fresh_pushes as u64;
+			self.stats.high_water_mark = self.stats.high_water_mark.max(self.vec.len());
+		}
 	}
-}
 
-impl<T> From<Vec<T>> for ReusableVec<T> {
 	#[inline]
-	fn from(vec: Vec<T>) -> Self {
-		Self { len: vec.len(), vec }
+	#[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+	fn record_clear(&mut self, reuse: bool) {
+		#[cfg(feature = "stats")]
+		if reuse {
+			self.stats.clear_reuses += 1;
+		} else {
+			self.stats.clear_drops += 1;
+		}
+	}
+
+	#[inline]
+	#[cfg_attr(not(feature = "dirty"), allow(unused_variables))]
+	fn mark_changed(&mut self, changed: Range<usize>) {
+		#[cfg(feature = "dirty")]
+		self.mark_dirty(changed);
+	}
+
+	#[inline]
+	fn enforce_reuse_limit(&mut self) {
+		if let Some(limit) = &self.limit {
+			let retained_len = limit.retained_len(&self.vec[self.len..]);
+			self.vec.truncate(self.len + retained_len);
+		}
+	}
+
+	#[inline]
+	#[track_caller]
+	fn assert_index(&self, index: usize) {
+		assert!(index < self.len, "index (is {index}) should be < len (is {})", self.len);
+	}
+
+	#[inline]
+	#[track_caller]
+	fn assert_insert_index(&self, index: usize) {
+		assert!(index <= self.len, "index (is {index}) should be <= len (is {})", self.len);
+	}
+
+	fn retain_live<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+		self.mark_moved(0);
+		let mut kept = 0;
+		let mut shifted_from = self.len;
+
+		for i in 0..self.len {
+			if f(&self.vec[i]) {
+				self.vec.swap(kept, i);
+				kept += 1;
+			} else {
+				shifted_from = shifted_from.min(i);
+			}
+		}
+
+		self.len = kept;
+		self.mark_changed(shifted_from..kept);
 	}
+
+	#[track_caller]
+	fn live_range<R: RangeBounds<usize>>(&self, range: R) -> Range<usize> {
+		let start = match range.start_bound() {
+			Bound::Included(&start) => start,
+			Bound::Excluded(&start) => start.checked_add(1).unwrap(),
+			Bound::Unbounded => 0,
+		};
+
+		let end = match range.end_bound() {
+			Bound::Included(&end) => end.checked_add(1).unwrap(),
+			Bound::Excluded(&end) => end,
+			Bound::Unbounded => self.len,
+		};
+
+		assert!(start <= end, "range start (is {start}) should be <= range end (is {end})");
+		assert!(end <= self.len, "range end (is {end}) should be <= len (is {})", self.len);
+		start..end
+	}
+}
+
+pub enum Slot<'a, T, A: Allocator = Global> {
+	Reused(&'a mut T),
+	Vacant(VacantSlot<'a, T, A>),
 }
 
-impl<T> From<ReusableVec<T>> for Vec<T> {
+impl<'a, T, A: Allocator> Slot<'a, T, A> {
 	#[inline]
-	fn from(reusable: ReusableVec<T>) -> Vec<T> {
-		reusable.into_vec()
+	pub fn or_insert_with<F: FnOnce() -> T>(self, create: F) -> &'a mut T {
+		match self {
+			Slot::Reused(reused) => reused,
+			Slot::Vacant(vacant) => vacant.insert(create()),
+		}
 	}
 }
 
-impl<T> std::ops::Deref for ReusableVec<T> {
-	type Target = [T];
+pub struct VacantSlot<'a, T, A: Allocator = Global> {
+	reusable: &'a mut ReusableVec<T, A>,
+}
 
+impl<'a, T, A: Allocator> VacantSlot<'a, T, A> {
 	#[inline]
-	fn deref(&self) -> &Self::Target {
-		self.as_slice()
+	pub fn insert(self, value: T) -> &'a mut T {
+		let index = self.reusable.len;
+		self.reusable.push(value);
+		&mut self.reusable.vec[index]
 	}
 }
 
-impl<T> std::ops::DerefMut for ReusableVec<T> {
+impl<T> Default for ReusableVec<T> {
 	#[inline]
-	fn deref_mut(&mut self) -> &mut Self::Target {
-		self.as_mut_slice()
+	fn default() -> Self {
+		Self::new()
 	}
 }
 
-impl<T> IntoIterator for ReusableVec<T> {
+impl<T, A: Allocator> Reusable for ReusableVec<T, A> {
 	type Item = T;
-	type IntoIter = <Vec<T> as IntoIterator>::IntoIter;
 
 	#[inline]
-	fn into_iter(self) -> Self::IntoIter {
-		self.into_vec().into_iter()
+	fn push_reuse(&mut self) -> Option<&mut T> {
+		self.push_reuse()
+	}
+
+	#[inline]
+	fn try_push(&mut self, value: T) -> Result<(), T> {
+		self.push(value);
+		Ok(())
+	}
+
+	#[inline]
+	fn reusable_len(&self) -> usize {
+		self.reusable_len()
+	}
+
+	#[inline]
+	fn clear_reuse(&mut self) {
+		self.clear_reuse();
+	}
+
+	#[inline]
+	fn clear_drop(&mut self) {
+		self.clear_drop();
+	}
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for ReusableVec<T, A> {
+	#[inline]
+	fn clone(&self) -> Self {
+		let mut vec = self.empty_vec(self.len);
+		vec.extend_from_slice(self.as_slice());
+		self.with_settings_of(Self::from_vec(vec))
+	}
+
+	fn clone_from(&mut self, source: &Self) {
+		self.limit.clone_from(&source.limit);
+		self.clear_reuse();
+
+		for value in source {
+			match self.push_slot() {
+				Slot::Reused(reused) => reused.clone_from(value),
+				Slot::Vacant(vacant) => {
+					vacant.insert(value.clone());
+				}
+			}
+		}
+	}
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for ReusableVec<T, A> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_slice(), f)
+	}
+}
+
+impl<T: PartialEq<U>, U, A: Allocator, B: Allocator> PartialEq<ReusableVec<U, B>> for ReusableVec<T, A> {
+	#[inline]
+	fn eq(&self, other: &ReusableVec<U, B>) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<[U]> for ReusableVec<T, A> {
+	#[inline]
+	fn eq(&self, other: &[U]) -> bool {
+		self.as_slice() == other
+	}
+}
+
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<Vec<U>> for ReusableVec<T, A> {
+	#[inline]
+	fn eq(&self, other: &Vec<U>) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+
+impl<T: PartialEq<U>, U, A: Allocator, const N: usize> PartialEq<[U; N]> for ReusableVec<T, A> {
+	#[inline]
+	fn eq(&self, other: &[U; N]) -> bool {
+		self.as_slice() == other
+	}
+}
+
+impl<T: Eq, A: Allocator> Eq for ReusableVec<T, A> {}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for ReusableVec<T, A> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		self.as_slice().partial_cmp(other.as_slice())
+	}
+}
+
+impl<T: Ord, A: Allocator> Ord for ReusableVec<T, A> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_slice().cmp(other.as_slice())
+	}
+}
+
+impl<T: Hash, A: Allocator> Hash for ReusableVec<T, A> {
+	#[inline]
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state);
+	}
+}
+
+impl<T, A: Allocator> Extend<T> for ReusableVec<T, A> {
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		let iter = iter.into_iter();
+		self.vec.reserve(iter.size_hint().0.saturating_sub(self.vec.len() - self.len));
+
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for ReusableVec<T, A> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+impl<T> FromIterator<T> for ReusableVec<T> {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		Self::from(Vec::from_iter(iter))
+	}
+}
+
+impl<T, A: Allocator> Deref for ReusableVec<T, A> {
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<T, A: Allocator> DerefMut for ReusableVec<T, A> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_slice()
 	}
 }
 
-impl<'a, T> IntoIterator for &'a ReusableVec<T> {
+impl<'a, T, A: Allocator> IntoIterator for &'a ReusableVec<T, A> {
 	type Item = &'a T;
 	type IntoIter = <&'a [T] as IntoIterator>::IntoIter;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
-		self.as_slice().into_iter()
+		self.as_slice().iter()
 	}
 }
 
-impl<'a, T> IntoIterator for &'a mut ReusableVec<T> {
+impl<'a, T, A: Allocator> IntoIterator for &'a mut ReusableVec<T, A> {
 	type Item = &'a mut T;
 	type IntoIter = <&'a mut [T] as IntoIterator>::IntoIter;
 
@@ -124,6 +790,8 @@ impl<'a, T> IntoIterator for &'a mut ReusableVec<T> {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use alloc::format;
+	use alloc::vec;
 
 	#[test]
 	fn it_should_work() {
@@ -138,7 +806,7 @@ mod tests {
 			let new_thing = Thing { cheap: 123, expensive: Vec::new() };
 
 			if let Some(reused) = things.push_reuse() {
-				let mut expensive = std::mem::take(&mut reused.expensive);
+				let mut expensive = core::mem::take(&mut reused.expensive);
 
 				if i > 0 {
 					assert_eq!(expensive, [456]);
@@ -169,4 +837,186 @@ mod tests {
 		things.clear_reuse();
 		assert_eq!(Vec::from(things).len(), 0);
 	}
+
+	#[test]
+	fn it_should_push_with_closures() {
+		let mut lists = ReusableVec::<Vec<u32>>::new();
+
+		for i in 0..3 {
+			let list = lists.push_with(Vec::clear, || Vec::with_capacity(100));
+			assert!(list.is_empty());
+			assert_eq!(list.capacity(), 100);
+			list.push(i);
+
+			match lists.push_slot() {
+				Slot::Reused(reused) => {
+					assert_eq!(*reused, [i - 1]);
+					reused.clear();
+					reused.push(i);
+				}
+				Slot::Vacant(vacant) => {
+					assert_eq!(i, 0);
+					vacant.insert(vec![i]);
+				}
+			}
+
+			assert_eq!(lists.as_slice(), [[i], [i]]);
+			lists.clear_reuse();
+		}
+
+		assert_eq!(lists.push_slot().or_insert_with(Vec::new), &[2]);
+	}
+
+	#[test]
+	fn it_should_extend_reusing_spare_slots() {
+		let mut lists = ReusableVec::from(vec![Vec::with_capacity(10)]);
+		lists.clear_reuse();
+
+		lists.extend_reuse(
+			1..4,
+			|list, item| {
+				list.clear();
+				list.push(item);
+			},
+			|item| vec![item],
+		);
+
+		assert_eq!(lists, [[1], [2], [3]]);
+		assert_eq!(lists[0].capacity(), 10);
+	}
+
+	#[test]
+	fn it_should_only_consider_live_values_in_traits() {
+		use std::collections::hash_map::DefaultHasher;
+
+		fn hash<T: Hash>(value: &T) -> u64 {
+			let mut hasher = DefaultHasher::new();
+			value.hash(&mut hasher);
+			hasher.finish()
+		}
+
+		let mut a = ReusableVec::from(vec![1, 2, 3]);
+		let mut b: ReusableVec<_> = [1, 2].into_iter().collect();
+		a.truncate_reuse(2);
+		assert_eq!(a, b);
+		assert_eq!(hash(&a), hash(&b));
+		assert_eq!(format!("{a:?}"), "[1, 2]");
+		assert_eq!(a.clone().push_reuse(), None);
+
+		b.extend(&[3, 4]);
+		assert!(a < b);
+		assert_eq!(b, [1, 2, 3, 4]);
+
+		let mut lists = ReusableVec::from(vec![Vec::with_capacity(100)]);
+		lists.clear_reuse();
+		lists.clone_from(&ReusableVec::from(vec![vec![1], vec![2]]));
+		assert_eq!(lists, [[1], [2]]);
+		assert!(lists[0].capacity() >= 100);
+	}
+
+	#[test]
+	fn it_should_move_removed_values_to_spare_slots() {
+		let mut values = ReusableVec::from(vec![0, 1, 2, 3, 4, 5]);
+
+		assert_eq!(*values.pop_reuse().unwrap(), 5);
+		assert_eq!(*values.remove_reuse(1), 1);
+		assert_eq!(*values.swap_remove_reuse(0), 0);
+		assert_eq!(values.as_slice(), [4, 2, 3]);
+		assert_eq!(*values.push_reuse().unwrap(), 0);
+		assert_eq!(*values.insert_reuse(0).unwrap(), 1);
+		assert_eq!(values.as_slice(), [1, 4, 2, 3, 0]);
+
+		values.retain_reuse(|&value| value % 2 == 0);
+		assert_eq!(values.as_slice(), [4, 2, 0]);
+		assert_eq!(values.drain_reuse(..2), [4, 2]);
+		assert_eq!(values.as_slice(), [0]);
+		values.truncate_reuse(0);
+		assert_eq!(values.len(), 0);
+
+		for _ in 0..6 {
+			values.push_reuse().unwrap();
+		}
+
+		assert!(values.push_reuse().is_none());
+		values.truncate_reuse(5);
+		let mut tail = values.split_off_reuse(3);
+		assert_eq!(values.len(), 3);
+		assert_eq!(tail.len(), 2);
+		assert!(tail.push_reuse().is_none());
+		assert!(values.push_reuse().is_some());
+	}
+
+	#[test]
+	fn it_should_be_covariant() {
+		fn shorten<'a>(values: ReusableVec<&'static str>) -> ReusableVec<&'a str> {
+			values
+		}
+
+		assert_eq!(shorten(ReusableVec::from(vec!["a"])), ["a"]);
+	}
+
+	#[test]
+	fn it_should_keep_spare_values_when_taken_apart() {
+		let mut values = ReusableVec::from_parts(vec![0, 1, 2, 3], 2);
+		assert_eq!(values.drain_live().collect::<Vec<_>>(), [0, 1]);
+		assert_eq!(values.len(), 0);
+		assert_eq!(*values.push_reuse().unwrap(), 2);
+		assert_eq!(values.into_parts(), (vec![2, 3], 1));
+	}
+
+	#[test]
+	fn it_should_manage_spare_slots() {
+		let mut lists = ReusableVec::<Vec<u32>>::new();
+		lists.reserve_reusable(3, || Vec::with_capacity(10));
+		assert_eq!(lists.len(), 0);
+		assert_eq!(lists.reusable_len(), 3);
+		assert_eq!(lists.total_len(), 3);
+
+		lists.push_reuse().unwrap().push(1);
+		lists.spare_slots_mut()[0].push(2);
+		assert_eq!(lists.spare_slots(), [vec![2], vec![]]);
+		lists.reserve_reusable(1, Vec::new);
+		assert_eq!(lists.total_len(), 3);
+
+		lists.shrink_reusable_to(1);
+		assert_eq!(lists.as_slice(), [[1]]);
+		assert_eq!(lists.spare_slots(), [[2]]);
+		lists.push_spare(vec![3]);
+		assert_eq!(lists.pop_spare(), Some(vec![3]));
+		assert_eq!(lists.pop_spare(), Some(vec![2]));
+		assert_eq!(lists.pop_spare(), None);
+		assert_eq!(lists.as_slice(), [[1]]);
+	}
+
+	#[test]
+	fn it_should_drop_removed_values() {
+		let mut values = ReusableVec::from(vec![0, 1, 2, 3, 4, 5]);
+		values.truncate_reuse(5);
+
+		assert_eq!(values.pop_drop(), Some(4));
+		assert_eq!(values.remove_drop(0), 0);
+		assert_eq!(values.swap_remove_drop(0), 1);
+		assert_eq!(values.as_slice(), [3, 2]);
+		values.insert(1, 6);
+		values.retain_drop(|&value| value != 3);
+		assert_eq!(values.as_slice(), [6, 2]);
+		assert_eq!(values.drain_drop(1..).collect::<Vec<_>>(), [2]);
+		values.truncate_drop(0);
+		assert!(values.push_reuse().is_none());
+		values.push(5);
+		values.clear_reuse();
+		assert_eq!(*values.push_reuse().unwrap(), 5);
+		assert_eq!(values.split_off_drop(0).as_slice(), [5]);
+		assert!(values.push_reuse().is_none());
+	}
+
+	#[test]
+	fn it_should_keep_spare_values_when_split_off() {
+		let mut values = ReusableVec::from_parts(vec![1, 2, 3, 4], 3);
+		assert_eq!(values.split_off_drop(2), [3]);
+		assert_eq!(values.as_slice(), [1, 2]);
+		assert_eq!(values.spare_slots(), [4]);
+		assert_eq!(values.split_off_reuse(1), [2]);
+		assert_eq!(values.spare_slots(), [4]);
+	}
 }
@@ -0,0 +1,189 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::{fmt, mem};
+
+pub trait HeapSize {
+	fn heap_size(&self) -> usize;
+}
+
+macro_rules! impl_heap_size_zero {
+	($($ty:ty),* $(,)?) => {
+		$(
+			impl HeapSize for $ty {
+				#[inline]
+				fn heap_size(&self) -> usize {
+					0
+				}
+			}
+		)*
+	};
+}
+
+impl_heap_size_zero! {
+	(), bool, char,
+	u8, u16, u32, u64, u128, usize,
+	i8, i16, i32, i64, i128, isize,
+	f32, f64,
+}
+
+impl HeapSize for String {
+	#[inline]
+	fn heap_size(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+	#[inline]
+	fn heap_size(&self) -> usize {
+		self.capacity() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+	}
+}
+
+impl<T: HeapSize> HeapSize for VecDeque<T> {
+	#[inline]
+	fn heap_size(&self) -> usize {
+		self.capacity() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+	}
+}
+
+impl<T: HeapSize> HeapSize for Box<T> {
+	#[inline]
+	fn heap_size(&self) -> usize {
+		mem::size_of::<T>() + (**self).heap_size()
+	}
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+	#[inline]
+	fn heap_size(&self) -> usize {
+		self.as_ref().map_or(0, T::heap_size)
+	}
+}
+
+// The estimator is stored with its argument type erased, so that `ReusableVec<T>` stays covariant in `T`
+pub struct ReuseLimit<T> {
+	max_items: usize,
+	max_bytes: usize,
+	heap_size: Option<unsafe fn(*const ()) -> usize>,
+	item: PhantomData<fn() -> T>,
+}
+
+impl<T> ReuseLimit<T> {
+	#[inline]
+	pub const fn unlimited() -> Self {
+		Self { max_items: usize::MAX, max_bytes: usize::MAX, heap_size: None, item: PhantomData }
+	}
+
+	#[inline]
+	pub const fn max_items(mut self, max_items: usize) -> Self {
+		self.max_items = max_items;
+		self
+	}
+
+	#[inline]
+	pub fn max_bytes(self, max_bytes: usize) -> Self
+	where
+		T: HeapSize,
+	{
+		self.max_bytes_with(max_bytes, T::heap_size)
+	}
+
+	#[inline]
+	pub const fn max_bytes_with(mut self, max_bytes: usize, heap_size: fn(&T) -> usize) -> Self {
+		self.max_bytes = max_bytes;
+		// SAFETY: `&T` and `*const ()` are ABI-compatible, and the estimator is only called with pointers to `T`
+		self.heap_size = Some(unsafe { mem::transmute::<fn(&T) -> usize, unsafe fn(*const ()) -> usize>(heap_size) });
+		self
+	}
+
+	#[inline]
+	pub(crate) fn is_unlimited(&self) -> bool {
+		self.max_items == usize::MAX && self.heap_size.is_none()
+	}
+
+	#[inline]
+	pub(crate) fn retained_len(&self, spare: &[T]) -> usize {
+		let len = spare.len().min(self.max_items);
+
+		let Some(heap_size) = self.heap_size else {
+			return len;
+		};
+
+		let mut bytes = 0usize;
+
+		spare[..len]
+			.iter()
+			.position(|value| {
+				// SAFETY: the pointer comes from a `&T`
+				bytes = bytes.saturating_add(unsafe { heap_size(value as *const T as *const ()) });
+				bytes > self.max_bytes
+			})
+			.unwrap_or(len)
+	}
+}
+
+impl<T> Clone for ReuseLimit<T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for ReuseLimit<T> {}
+
+impl<T> Default for ReuseLimit<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::unlimited()
+	}
+}
+
+impl<T> fmt::Debug for ReuseLimit<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ReuseLimit")
+			.field("max_items", &self.max_items)
+			.field("max_bytes", &self.heap_size.map(|_| self.max_bytes))
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ReusableVec;
+
+	#[test]
+	fn it_should_drop_spare_values_over_limit() {
+		let mut lists = ReusableVec::<Vec<u32>>::new();
+		lists.set_reuse_limit(ReuseLimit::unlimited().max_items(4));
+		lists.reserve_reusable(5, || Vec::with_capacity(4));
+		assert_eq!(lists.reusable_len(), 5);
+
+		lists.clear_reuse();
+		assert_eq!(lists.reusable_len(), 4);
+
+		lists.push_reuse().unwrap().push(1);
+		lists.set_reuse_limit(ReuseLimit::unlimited().max_bytes(40));
+		assert_eq!(lists.reusable_len(), 2);
+
+		lists.truncate_reuse(0);
+		assert_eq!(lists.reusable_len(), 2);
+
+		lists.extend([Vec::new(), Vec::new(), Vec::new()]);
+		lists.retain_reuse(|_| false);
+		assert_eq!(lists.reusable_len(), 3);
+	}
+
+	#[test]
+	fn it_should_retain_drop_under_limit() {
+		let mut values = ReusableVec::from(alloc::vec![1, 2, 3]);
+		values.set_reuse_limit(ReuseLimit::unlimited().max_items(0));
+		values.retain_drop(|&value| value != 2);
+		assert_eq!(values.as_slice(), [1, 3]);
+		assert_eq!(values.reusable_len(), 0);
+	}
+}
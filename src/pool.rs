@@ -0,0 +1,241 @@
+use std::mem::ManuallyDrop;
+use std::num::NonZeroUsize;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError, TryLockError};
+use std::{fmt, thread};
+
+use crate::{Reset, ReusableVec, ReuseLimit};
+
+pub struct ReusablePool<T, F = fn() -> T> {
+	shards: Box<[Mutex<ReusableVec<T>>]>,
+	create: F,
+	hits: AtomicU64,
+	misses: AtomicU64,
+	returns: AtomicU64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolStats {
+	pub hits: u64,
+	pub misses: u64,
+	pub returns: u64,
+}
+
+impl<T: Reset + Default> ReusablePool<T> {
+	#[inline]
+	pub fn new() -> Self {
+		Self::with_create(T::default)
+	}
+}
+
+impl<T: Reset, F: Fn() -> T> ReusablePool<T, F> {
+	#[inline]
+	pub fn with_create(create: F) -> Self {
+		Self::with_shards(thread::available_parallelism().map_or(1, NonZeroUsize::get), create)
+	}
+
+	pub fn with_shards(shard_count: usize, create: F) -> Self {
+		Self {
+			shards: (0..shard_count.max(1)).map(|_| Mutex::new(ReusableVec::new())).collect(),
+			create,
+			hits: AtomicU64::new(0),
+			misses: AtomicU64::new(0),
+			returns: AtomicU64::new(0),
+		}
+	}
+
+	pub fn get(&self) -> Pooled<'_, T, F> {
+		let shard = Self::shard_index(self.shards.len());
+		let value = self.lock(shard).pop_spare();
+
+		let value = match value.or_else(|| self.steal(shard)) {
+			Some(value) => {
+				self.hits.fetch_add(1, Ordering::Relaxed);
+				value
+			}
+			None => {
+				self.misses.fetch_add(1, Ordering::Relaxed);
+				(self.create)()
+			}
+		};
+
+		Pooled { pool: self, value: ManuallyDrop::new(value) }
+	}
+
+	#[inline]
+	pub fn put(&self, mut value: T) {
+		value.reset();
+		self.returns.fetch_add(1, Ordering::Relaxed);
+		self.lock(Self::shard_index(self.shards.len())).push_spare(value);
+	}
+
+	pub fn set_reuse_limit(&self, limit: ReuseLimit<T>) {
+		for shard in self.shards.iter() {
+			shard.lock().unwrap_or_else(PoisonError::into_inner).set_reuse_limit(limit);
+		}
+	}
+
+	pub fn available(&self) -> usize {
+		self.shards.iter().map(|shard| shard.lock().unwrap_or_else(PoisonError::into_inner).reusable_len()).sum()
+	}
+
+	#[inline]
+	pub fn stats(&self) -> PoolStats {
+		PoolStats {
+			hits: self.hits.load(Ordering::Relaxed),
+			misses: self.misses.load(Ordering::Relaxed),
+			returns: self.returns.load(Ordering::Relaxed),
+		}
+	}
+
+	#[inline]
+	fn lock(&self, shard: usize) -> MutexGuard<'_, ReusableVec<T>> {
+		self.shards[shard].lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	fn steal(&self, own_shard: usize) -> Option<T> {
+		(1..self.shards.len()).find_map(|offset| {
+			match self.shards[(own_shard + offset) % self.shards.len()].try_lock() {
+				Ok(mut shard) => shard.pop_spare(),
+				Err(TryLockError::Poisoned(error)) => error.into_inner().pop_spare(),
+				Err(TryLockError::WouldBlock) => None,
+			}
+		})
+	}
+
+	#[inline]
+	fn shard_index(shard_count: usize) -> usize {
+		static NEXT_SHARD: AtomicUsize = AtomicUsize::new(0);
+
+		thread_local! {
+			static SHARD: usize = NEXT_SHARD.fetch_add(1, Ordering::Relaxed);
+		}
+
+		SHARD.with(|shard| *shard) % shard_count
+	}
+}
+
+impl<T: Reset + Default> Default for ReusablePool<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, F> fmt::Debug for ReusablePool<T, F> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ReusablePool").field("shards", &self.shards.len()).finish_non_exhaustive()
+	}
+}
+
+pub struct Pooled<'a, T: Reset, F: Fn() -> T = fn() -> T> {
+	pool: &'a ReusablePool<T, F>,
+	value: ManuallyDrop<T>,
+}
+
+impl<T: Reset, F: Fn() -> T> Pooled<'_, T, F> {
+	#[inline]
+	pub fn into_inner(this: Self) -> T {
+		let mut this = ManuallyDrop::new(this);
+		// SAFETY: `this` is never used or dropped again
+		unsafe { ManuallyDrop::take(&mut this.value) }
+	}
+}
+
+impl<T: Reset, F: Fn() -> T> Deref for Pooled<'_, T, F> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T: Reset, F: Fn() -> T> DerefMut for Pooled<'_, T, F> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.value
+	}
+}
+
+impl<T: Reset + fmt::Debug, F: Fn() -> T> fmt::Debug for Pooled<'_, T, F> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&*self.value, f)
+	}
+}
+
+impl<T: Reset, F: Fn() -> T> Drop for Pooled<'_, T, F> {
+	#[inline]
+	fn drop(&mut self) {
+		// SAFETY: `self.value` is never used again
+		self.pool.put(unsafe { ManuallyDrop::take(&mut self.value) });
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::mpsc;
+
+	#[test]
+	fn it_should_recycle_values_across_threads() {
+		let pool = ReusablePool::with_shards(2, || Vec::<u32>::with_capacity(10));
+
+		{
+			let mut list = pool.get();
+			list.push(1);
+			assert_eq!(*list, [1]);
+		}
+
+		let list = pool.get();
+		assert!(list.is_empty());
+		assert_eq!(list.capacity(), 10);
+		assert_eq!(Pooled::into_inner(list).capacity(), 10);
+		assert_eq!(pool.stats(), PoolStats { hits: 1, misses: 1, returns: 1 });
+
+		thread::scope(|scope| {
+			for _ in 0..4 {
+				scope.spawn(|| {
+					for i in 0..100 {
+						pool.get().push(i);
+					}
+				});
+			}
+		});
+
+		let stats = pool.stats();
+		assert_eq!(stats.hits + stats.misses, 402);
+		assert_eq!(stats.returns, 401);
+		assert_eq!(pool.available() as u64, stats.misses - 1);
+	}
+
+	#[test]
+	fn it_should_reuse_values_returned_by_another_thread() {
+		let pool = ReusablePool::<Vec<u32>>::with_shards(4, Vec::new);
+		let (values_sender, values_receiver) = mpsc::channel();
+		let (dropped_sender, dropped_receiver) = mpsc::channel();
+
+		thread::scope(|scope| {
+			let pool = &pool;
+
+			scope.spawn(move || {
+				for _ in 0..10 {
+					let values: Vec<_> = (0..10).map(|_| pool.get()).collect();
+					values_sender.send(values).unwrap();
+					dropped_receiver.recv().unwrap();
+				}
+			});
+
+			scope.spawn(move || {
+				for values in values_receiver {
+					drop(values);
+					dropped_sender.send(()).unwrap();
+				}
+			});
+		});
+
+		assert_eq!(pool.stats(), PoolStats { hits: 90, misses: 10, returns: 100 });
+	}
+}
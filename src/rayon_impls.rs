@@ -0,0 +1,73 @@
+use rayon::prelude::*;
+
+use crate::allocator::Allocator;
+use crate::ReusableVec;
+
+impl<'a, T: Sync, A: Allocator> IntoParallelIterator for &'a ReusableVec<T, A> {
+	type Iter = rayon::slice::Iter<'a, T>;
+	type Item = &'a T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		self.as_slice().into_par_iter()
+	}
+}
+
+impl<'a, T: Send, A: Allocator> IntoParallelIterator for &'a mut ReusableVec<T, A> {
+	type Iter = rayon::slice::IterMut<'a, T>;
+	type Item = &'a mut T;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		self.as_mut_slice().into_par_iter()
+	}
+}
+
+impl<T: Send> ReusableVec<T> {
+	pub fn par_extend_reuse<R, C>(&mut self, count: usize, reset: R, create: C)
+	where
+		R: Fn(usize, &mut T) + Sync,
+		C: Fn(usize) -> T + Sync,
+	{
+		let old_len = self.len;
+		let reused = count.min(self.reusable_len());
+		self.mark_reused(old_len..old_len + reused);
+
+		self.vec[old_len..old_len + reused]
+			.par_iter_mut()
+			.enumerate()
+			.for_each(|(index, slot)| reset(index, slot));
+
+		self.vec.par_extend((reused..count).into_par_iter().map(&create));
+		self.len += count;
+		self.record_reuses(reused, count - reused);
+		self.record_growth(count - reused);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn it_should_extend_reusing_spare_slots_in_parallel() {
+		let mut lists = ReusableVec::<Vec<usize>>::new();
+		lists.reserve_reusable(100, || Vec::with_capacity(10));
+
+		lists.par_extend_reuse(
+			1000,
+			|index, list| {
+				list.clear();
+				list.push(index);
+			},
+			|index| vec![index],
+		);
+
+		assert_eq!(lists.len(), 1000);
+		assert!(lists.par_iter().enumerate().all(|(index, list)| *list == [index]));
+		assert_eq!(lists.iter().filter(|list| list.capacity() == 10).count(), 100);
+
+		(&mut lists).into_par_iter().for_each(|list| list[0] *= 2);
+		assert_eq!(lists[999], [1998]);
+	}
+}
@@ -0,0 +1,160 @@
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+pub trait Reset {
+	fn reset(&mut self);
+}
+
+macro_rules! impl_reset_clear {
+	($($ty:ident<$($param:ident),*>),* $(,)?) => {
+		$(
+			impl<$($param),*> Reset for $ty<$($param),*> {
+				#[inline]
+				fn reset(&mut self) {
+					self.clear();
+				}
+			}
+		)*
+	};
+}
+
+impl_reset_clear! {
+	Vec<T>,
+	VecDeque<T>,
+	LinkedList<T>,
+	BinaryHeap<T>,
+	BTreeMap<K, V>,
+	BTreeSet<T>,
+}
+
+#[cfg(feature = "std")]
+impl_reset_clear! {
+	HashMap<K, V, S>,
+	HashSet<T, S>,
+}
+
+impl Reset for String {
+	#[inline]
+	fn reset(&mut self) {
+		self.clear();
+	}
+}
+
+impl<T> Reset for Option<T> {
+	#[inline]
+	fn reset(&mut self) {
+		*self = None;
+	}
+}
+
+impl<T: Reset + ?Sized> Reset for Box<T> {
+	#[inline]
+	fn reset(&mut self) {
+		(**self).reset();
+	}
+}
+
+impl<T: Reset, const N: usize> Reset for [T; N] {
+	#[inline]
+	fn reset(&mut self) {
+		self.iter_mut().for_each(T::reset);
+	}
+}
+
+macro_rules! impl_reset_default {
+	($($ty:ty),* $(,)?) => {
+		$(
+			impl Reset for $ty {
+				#[inline]
+				fn reset(&mut self) {
+					*self = Default::default();
+				}
+			}
+		)*
+	};
+}
+
+impl_reset_default! {
+	(), bool, char,
+	u8, u16, u32, u64, u128, usize,
+	i8, i16, i32, i64, i128, isize,
+	f32, f64,
+}
+
+macro_rules! impl_reset_tuple {
+	($($param:ident $index:tt),+) => {
+		impl<$($param: Reset),+> Reset for ($($param,)+) {
+			#[inline]
+			fn reset(&mut self) {
+				$(self.$index.reset();)+
+			}
+		}
+	};
+}
+
+impl_reset_tuple!(A 0);
+impl_reset_tuple!(A 0, B 1);
+impl_reset_tuple!(A 0, B 1, C 2);
+impl_reset_tuple!(A 0, B 1, C 2, D 3);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_reset_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ReusableVec;
+
+	#[test]
+	fn it_should_push_reset_values() {
+		let mut values = ReusableVec::<(Vec<u32>, String, Option<u8>, u64)>::new();
+
+		for _ in 0..2 {
+			let (list, string, option, number) = values.push_reset();
+			assert!(list.is_empty() && string.is_empty() && option.is_none() && *number == 0);
+			list.reserve(100);
+			list.push(1);
+			string.push('a');
+			*option = Some(2);
+			*number = 3;
+			values.clear_reuse();
+		}
+
+		assert!(values.push_reset().0.capacity() >= 100);
+	}
+
+	#[cfg(feature = "derive")]
+	#[test]
+	fn it_should_derive_reset() {
+		use crate::Reset;
+		use alloc::vec;
+
+		struct NotReset;
+
+		#[derive(Reset)]
+		struct Thing<T> {
+			list: Vec<T>,
+			#[reset(default)]
+			cheap: core::ops::Range<u32>,
+			#[reset(default)]
+			last: Option<T>,
+			#[reset(skip)]
+			id: u32,
+		}
+
+		let mut thing = Thing { list: vec![NotReset], cheap: 1..2, last: Some(NotReset), id: 3 };
+		thing.reset();
+		assert!(thing.list.is_empty() && thing.last.is_none());
+		assert_eq!(thing.cheap, 0..0);
+		assert_eq!(thing.id, 3);
+	}
+}
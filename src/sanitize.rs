@@ -0,0 +1,162 @@
+use core::marker::PhantomData;
+use core::mem;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::allocator::Allocator;
+use crate::ReusableVec;
+
+pub trait Poison {
+	fn poison(&mut self);
+	fn is_poisoned(&self) -> bool;
+}
+
+// Like `ReuseLimit`, the functions are stored with their argument types erased to keep `ReusableVec<T>` covariant
+pub(crate) struct Sanitizer<T> {
+	poison: unsafe fn(*mut ()),
+	is_poisoned: unsafe fn(*const ()) -> bool,
+	unchecked_from: AtomicUsize,
+	item: PhantomData<fn() -> T>,
+}
+
+impl<T> Sanitizer<T> {
+	#[inline]
+	fn new(poison: fn(&mut T), is_poisoned: fn(&T) -> bool) -> Self {
+		// SAFETY: `&mut T` and `&T` are ABI-compatible with `*mut ()` and `*const ()`, and the functions are only called
+		// with pointers to `T`
+		unsafe {
+			Self {
+				poison: mem::transmute::<fn(&mut T), unsafe fn(*mut ())>(poison),
+				is_poisoned: mem::transmute::<fn(&T) -> bool, unsafe fn(*const ()) -> bool>(is_poisoned),
+				unchecked_from: AtomicUsize::new(usize::MAX),
+				item: PhantomData,
+			}
+		}
+	}
+
+	#[inline]
+	pub(crate) fn poison(&self, reused: &mut [T]) {
+		for value in reused {
+			// SAFETY: the pointer comes from a `&mut T`
+			unsafe { (self.poison)(value as *mut T as *mut ()) }
+		}
+	}
+
+	#[inline]
+	pub(crate) fn mark_reused(&self, index: usize) {
+		self.unchecked_from.fetch_min(index, Ordering::Relaxed);
+	}
+
+	#[inline]
+	pub(crate) fn mark_moved(&self, index: usize) {
+		if self.unchecked_from.load(Ordering::Relaxed) != usize::MAX {
+			self.mark_reused(index);
+		}
+	}
+
+	#[cfg(debug_assertions)]
+	#[track_caller]
+	pub(crate) fn check(&self, live: &[T]) {
+		let unchecked_from = self.unchecked_from.swap(usize::MAX, Ordering::Relaxed);
+
+		for (index, value) in live.iter().enumerate().skip(unchecked_from) {
+			assert!(
+				// SAFETY: the pointer comes from a `&T`
+				!unsafe { (self.is_poisoned)(value as *const T as *const ()) },
+				"item {index} of ReusableVec<{}> was reused without being reinitialized",
+				core::any::type_name::<T>(),
+			);
+		}
+	}
+}
+
+impl<T> Clone for Sanitizer<T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			poison: self.poison,
+			is_poisoned: self.is_poisoned,
+			unchecked_from: AtomicUsize::new(usize::MAX),
+			item: PhantomData,
+		}
+	}
+}
+
+impl<T, A: Allocator> ReusableVec<T, A> {
+	pub fn enable_sanitizer(&mut self)
+	where
+		T: Poison,
+	{
+		self.sanitizer = Some(Sanitizer::new(T::poison, T::is_poisoned));
+	}
+
+	#[inline]
+	pub fn disable_sanitizer(&mut self) {
+		self.sanitizer = None;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+	use alloc::vec::Vec;
+
+	struct Thing {
+		cheap: u32,
+		expensive: Vec<u32>,
+	}
+
+	impl Poison for Thing {
+		fn poison(&mut self) {
+			self.cheap = 0xDEAD;
+		}
+
+		fn is_poisoned(&self) -> bool {
+			self.cheap == 0xDEAD
+		}
+	}
+
+	fn things() -> ReusableVec<Thing> {
+		let mut things = ReusableVec::from(vec![
+			Thing { cheap: 1, expensive: vec![2] },
+			Thing { cheap: 3, expensive: vec![4] },
+		]);
+		things.enable_sanitizer();
+		things.clear_reuse();
+		things
+	}
+
+	#[test]
+	fn it_should_allow_reinitialized_reused_items() {
+		let mut things = things();
+		assert!(things.push_reuse().unwrap().is_poisoned());
+		assert_eq!(things.len(), 1);
+		things.last_mut().unwrap().cheap = 5;
+		things.last_mut().unwrap().expensive.clear();
+		assert_eq!(things[0].cheap, 5);
+	}
+
+	#[cfg(debug_assertions)]
+	#[test]
+	#[should_panic(expected = "item 1 of ReusableVec<reusable_vec::sanitize::tests::Thing> was reused")]
+	fn it_should_detect_stale_reused_items() {
+		let mut things = things();
+		things.push_reuse().unwrap().cheap = 5;
+		things.push_reuse().unwrap().expensive.clear();
+		let _ = things.iter().map(|thing| thing.cheap).sum::<u32>();
+	}
+
+	#[test]
+	fn it_should_keep_removed_items_intact() {
+		let mut things = things();
+		things.push_reuse().unwrap().cheap = 5;
+		things.push_reuse().unwrap().cheap = 6;
+		assert_eq!(things.pop_reuse().unwrap().cheap, 6);
+		assert_eq!(things.push_reuse().unwrap().cheap, 0xDEAD);
+		things[1].cheap = 7;
+
+		let drained = things.drain_reuse(..2);
+		assert_eq!((drained[0].cheap, drained[1].cheap), (5, 7));
+		assert_eq!(drained[1].expensive, [4]);
+	}
+}
@@ -0,0 +1,110 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::allocator::Allocator;
+use crate::ReusableVec;
+
+impl<T: Serialize, A: Allocator> Serialize for ReusableVec<T, A> {
+	#[inline]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.as_slice().serialize(serializer)
+	}
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ReusableVec<T> {
+	#[inline]
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Vec::deserialize(deserializer).map(Self::from)
+	}
+
+	#[inline]
+	fn deserialize_in_place<D: Deserializer<'de>>(deserializer: D, place: &mut Self) -> Result<(), D::Error> {
+		deserializer.deserialize_seq(InPlaceVisitor(place))
+	}
+}
+
+struct InPlaceVisitor<'a, T>(&'a mut ReusableVec<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for InPlaceVisitor<'_, T> {
+	type Value = ();
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a sequence")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let reusable = self.0;
+		reusable.clear_reuse();
+
+		// The spare item is only claimed once an element was read into it, so the end of the sequence isn't a reuse
+		loop {
+			if let Some(spare) = reusable.vec.get_mut(reusable.len) {
+				if seq.next_element_seed(InPlaceSeed(spare))?.is_none() {
+					return Ok(());
+				}
+
+				reusable.len += 1;
+				reusable.record_reuse(true);
+			} else {
+				let Some(value) = seq.next_element()? else {
+					return Ok(());
+				};
+
+				reusable.record_reuse(false);
+				reusable.push(value);
+			}
+		}
+	}
+}
+
+struct InPlaceSeed<'a, T>(&'a mut T);
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for InPlaceSeed<'_, T> {
+	type Value = ();
+
+	#[inline]
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		T::deserialize_in_place(deserializer, self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::string::String;
+	use alloc::vec;
+
+	#[test]
+	fn it_should_serialize_live_values_and_deserialize_into_spare_slots() {
+		let mut strings = ReusableVec::from(vec![String::with_capacity(100), String::from("stale")]);
+		strings.truncate_reuse(1);
+		assert_eq!(serde_json::to_string(&strings).unwrap(), r#"[""]"#);
+
+		for json in [r#"["a","b","c"]"#, r#"["d"]"#] {
+			let mut deserializer = serde_json::Deserializer::from_str(json);
+			ReusableVec::deserialize_in_place(&mut deserializer, &mut strings).unwrap();
+			assert_eq!(serde_json::to_string(&strings).unwrap(), json);
+			assert!(strings[0].capacity() >= 100);
+		}
+
+		assert_eq!(strings.push_reuse().map(|string| string.as_str()), Some("b"));
+		let strings: ReusableVec<String> = serde_json::from_str(r#"["e"]"#).unwrap();
+		assert_eq!(strings, ["e"]);
+	}
+
+	#[cfg(feature = "stats")]
+	#[test]
+	fn it_should_only_count_deserialized_items_as_reused() {
+		let mut strings = ReusableVec::from(vec![String::new(), String::new()]);
+		let mut deserializer = serde_json::Deserializer::from_str(r#"["a"]"#);
+		ReusableVec::deserialize_in_place(&mut deserializer, &mut strings).unwrap();
+		assert_eq!((strings.stats().reuse_hits, strings.stats().reuse_misses), (1, 0));
+
+		let mut deserializer = serde_json::Deserializer::from_str(r#"["b","c","d"]"#);
+		ReusableVec::deserialize_in_place(&mut deserializer, &mut strings).unwrap();
+		assert_eq!((strings.stats().reuse_hits, strings.stats().reuse_misses), (3, 1));
+	}
+}
@@ -0,0 +1,318 @@
+use alloc::vec::Vec;
+use core::iter::{Enumerate, FusedIterator};
+use core::ops::{Index, IndexMut};
+use core::{fmt, slice};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Handle {
+	index: usize,
+	generation: u32,
+}
+
+impl Handle {
+	#[inline]
+	pub fn index(self) -> usize {
+		self.index
+	}
+
+	#[inline]
+	pub fn generation(self) -> u32 {
+		self.generation
+	}
+}
+
+struct Entry<T> {
+	value: Option<T>,
+	generation: u32,
+	occupied: bool,
+}
+
+pub struct ReusableSlab<T> {
+	entries: Vec<Entry<T>>,
+	parked: Vec<usize>,
+	vacant: Vec<usize>,
+	len: usize,
+}
+
+impl<T> ReusableSlab<T> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { entries: Vec::new(), parked: Vec::new(), vacant: Vec::new(), len: 0 }
+	}
+
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { entries: Vec::with_capacity(capacity), parked: Vec::new(), vacant: Vec::new(), len: 0 }
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	#[inline]
+	pub fn reusable_len(&self) -> usize {
+		self.parked.len()
+	}
+
+	#[inline]
+	pub fn insert_reuse(&mut self) -> Option<(Handle, &mut T)> {
+		let index = self.parked.pop()?;
+		Some(self.occupy(index))
+	}
+
+	pub fn insert(&mut self, value: T) -> Handle {
+		let index = match self.vacant.pop().or_else(|| self.parked.pop()) {
+			Some(index) => {
+				self.entries[index].value = Some(value);
+				index
+			}
+			None => {
+				self.entries.push(Entry { value: Some(value), generation: 0, occupied: false });
+				self.entries.len() - 1
+			}
+		};
+
+		self.occupy(index).0
+	}
+
+	#[inline]
+	pub fn insert_with<R, C>(&mut self, reset: R, create: C) -> (Handle, &mut T)
+	where
+		R: FnOnce(&mut T),
+		C: FnOnce() -> T,
+	{
+		if let Some(index) = self.parked.pop() {
+			let (handle, reused) = self.occupy(index);
+			reset(reused);
+			(handle, reused)
+		} else {
+			let handle = self.insert(create());
+			(handle, self.entries[handle.index].value.as_mut().unwrap())
+		}
+	}
+
+	#[inline]
+	pub fn contains(&self, handle: Handle) -> bool {
+		self.get(handle).is_some()
+	}
+
+	#[inline]
+	pub fn get(&self, handle: Handle) -> Option<&T> {
+		let entry = self.entries.get(handle.index)?;
+
+		if entry.occupied && entry.generation == handle.generation {
+			entry.value.as_ref()
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+		let entry = self.entries.get_mut(handle.index)?;
+
+		if entry.occupied && entry.generation == handle.generation {
+			entry.value.as_mut()
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	pub fn remove_reuse(&mut self, handle: Handle) -> bool {
+		let removed = self.contains(handle);
+
+		if removed {
+			self.release(handle.index, false);
+		}
+
+		removed
+	}
+
+	#[inline]
+	pub fn remove_drop(&mut self, handle: Handle) -> Option<T> {
+		if !self.contains(handle) {
+			return None;
+		}
+
+		let value = self.entries[handle.index].value.take();
+		self.release(handle.index, true);
+		value
+	}
+
+	#[inline]
+	pub fn iter(&self) -> Iter<'_, T> {
+		Iter { entries: self.entries.iter().enumerate(), len: self.len }
+	}
+
+	pub fn clear_reuse(&mut self) {
+		for index in 0..self.entries.len() {
+			if self.entries[index].occupied {
+				self.release(index, false);
+			}
+		}
+	}
+
+	pub fn clear_drop(&mut self) {
+		self.parked.clear();
+
+		for index in 0..self.entries.len() {
+			if self.entries[index].occupied {
+				self.release(index, true);
+			} else if self.entries[index].value.take().is_some() {
+				self.vacant.push(index);
+			}
+		}
+	}
+
+	#[inline]
+	fn occupy(&mut self, index: usize) -> (Handle, &mut T) {
+		let entry = &mut self.entries[index];
+		entry.occupied = true;
+		self.len += 1;
+		(Handle { index, generation: entry.generation }, entry.value.as_mut().unwrap())
+	}
+
+	#[inline]
+	fn release(&mut self, index: usize, drop: bool) {
+		let entry = &mut self.entries[index];
+		entry.occupied = false;
+		self.len -= 1;
+
+		if drop {
+			entry.value = None;
+		}
+
+		// A slot whose generation can't be incremented anymore is retired, so that its handles never alias
+		match entry.generation.checked_add(1) {
+			Some(generation) => {
+				entry.generation = generation;
+
+				if entry.value.is_some() {
+					self.parked.push(index);
+				} else {
+					self.vacant.push(index);
+				}
+			}
+			None => entry.value = None,
+		}
+	}
+}
+
+impl<T> Default for ReusableSlab<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for ReusableSlab<T> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<T> Index<Handle> for ReusableSlab<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, handle: Handle) -> &T {
+		self.get(handle).expect("invalid handle")
+	}
+}
+
+impl<T> IndexMut<Handle> for ReusableSlab<T> {
+	#[inline]
+	fn index_mut(&mut self, handle: Handle) -> &mut T {
+		self.get_mut(handle).expect("invalid handle")
+	}
+}
+
+impl<'a, T> IntoIterator for &'a ReusableSlab<T> {
+	type Item = (Handle, &'a T);
+	type IntoIter = Iter<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+pub struct Iter<'a, T> {
+	entries: Enumerate<slice::Iter<'a, Entry<T>>>,
+	len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+	type Item = (Handle, &'a T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		for (index, entry) in self.entries.by_ref() {
+			if entry.occupied {
+				self.len -= 1;
+				return Some((Handle { index, generation: entry.generation }, entry.value.as_ref().unwrap()));
+			}
+		}
+
+		None
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+
+	#[test]
+	fn it_should_reuse_slots_without_aliasing() {
+		let mut slab = ReusableSlab::new();
+		let a = slab.insert(vec![1]);
+		let b = slab.insert(vec![2]);
+		assert_eq!(slab[a], [1]);
+
+		assert!(slab.remove_reuse(a));
+		assert!(!slab.remove_reuse(a));
+		assert_eq!(slab.get(a), None);
+		assert_eq!(slab.reusable_len(), 1);
+
+		let (c, reused) = slab.insert_reuse().unwrap();
+		assert_eq!(*reused, [1]);
+		assert_eq!(c.index(), a.index());
+		assert_ne!(c, a);
+		assert_eq!(slab.get(a), None);
+		assert_eq!(slab.iter().map(|(handle, _)| handle).collect::<Vec<_>>(), [c, b]);
+
+		slab.clear_drop();
+		assert!(slab.is_empty());
+		assert!(slab.insert_reuse().is_none());
+		let d = slab.insert(vec![3]);
+		assert!(slab.get(b).is_none() && slab.get(c).is_none());
+		assert_eq!(slab.get(d), Some(&vec![3]));
+		assert_eq!(slab.remove_drop(d), Some(vec![3]));
+
+		let e = slab.insert(vec![4]);
+		slab.entries[e.index()].generation = u32::MAX;
+		let e = Handle { generation: u32::MAX, ..e };
+		slab.clear_reuse();
+		assert_eq!(slab.reusable_len(), 0);
+		assert_ne!(slab.insert(vec![5]).index(), e.index());
+		assert!(slab.get(e).is_none());
+	}
+}
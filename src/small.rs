@@ -0,0 +1,208 @@
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+use crate::{Reusable, ReusableArrayVec, ReusableVec};
+
+pub struct ReusableSmallVec<T, const N: usize> {
+	storage: Storage<T, N>,
+	unspill_on_clear_drop: bool,
+}
+
+enum Storage<T, const N: usize> {
+	Inline(ReusableArrayVec<T, N>),
+	Heap(ReusableVec<T>),
+}
+
+impl<T, const N: usize> ReusableSmallVec<T, N> {
+	#[inline]
+	pub const fn new() -> Self {
+		Self { storage: Storage::Inline(ReusableArrayVec::new()), unspill_on_clear_drop: false }
+	}
+
+	#[inline]
+	pub fn spilled(&self) -> bool {
+		matches!(self.storage, Storage::Heap(_))
+	}
+
+	#[inline]
+	pub fn set_unspill_on_clear_drop(&mut self, unspill: bool) {
+		self.unspill_on_clear_drop = unspill;
+	}
+
+	#[inline]
+	pub fn push_reuse(&mut self) -> Option<&mut T> {
+		match &mut self.storage {
+			Storage::Inline(array) => array.push_reuse(),
+			Storage::Heap(vec) => vec.push_reuse(),
+		}
+	}
+
+	#[inline]
+	pub fn push(&mut self, value: T) {
+		match &mut self.storage {
+			Storage::Inline(array) => {
+				if let Err(value) = array.push(value) {
+					let (mut vec, len) = array.take_parts(N.saturating_mul(2));
+					vec.push(value);
+					self.storage = Storage::Heap(ReusableVec::from_parts(vec, len + 1));
+				}
+			}
+			Storage::Heap(vec) => vec.push(value),
+		}
+	}
+
+	#[inline]
+	pub fn as_slice(&self) -> &[T] {
+		match &self.storage {
+			Storage::Inline(array) => array.as_slice(),
+			Storage::Heap(vec) => vec.as_slice(),
+		}
+	}
+
+	#[inline]
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		match &mut self.storage {
+			Storage::Inline(array) => array.as_mut_slice(),
+			Storage::Heap(vec) => vec.as_mut_slice(),
+		}
+	}
+
+	#[inline]
+	pub fn reusable_len(&self) -> usize {
+		match &self.storage {
+			Storage::Inline(array) => array.reusable_len(),
+			Storage::Heap(vec) => vec.reusable_len(),
+		}
+	}
+
+	#[inline]
+	pub fn clear_reuse(&mut self) {
+		match &mut self.storage {
+			Storage::Inline(array) => array.clear_reuse(),
+			Storage::Heap(vec) => vec.clear_reuse(),
+		}
+	}
+
+	#[inline]
+	pub fn clear_drop(&mut self) {
+		match &mut self.storage {
+			Storage::Inline(array) => array.clear_drop(),
+			Storage::Heap(_) if self.unspill_on_clear_drop => self.storage = Storage::Inline(ReusableArrayVec::new()),
+			Storage::Heap(vec) => vec.clear_drop(),
+		}
+	}
+}
+
+impl<T, const N: usize> Default for ReusableSmallVec<T, N> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ReusableSmallVec<T, N> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_slice(), f)
+	}
+}
+
+impl<T, const N: usize> Deref for ReusableSmallVec<T, N> {
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> DerefMut for ReusableSmallVec<T, N> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_slice()
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ReusableSmallVec<T, N> {
+	type Item = &'a T;
+	type IntoIter = <&'a [T] as IntoIterator>::IntoIter;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_slice().iter()
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ReusableSmallVec<T, N> {
+	type Item = &'a mut T;
+	type IntoIter = <&'a mut [T] as IntoIterator>::IntoIter;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T, const N: usize> Reusable for ReusableSmallVec<T, N> {
+	type Item = T;
+
+	#[inline]
+	fn push_reuse(&mut self) -> Option<&mut T> {
+		self.push_reuse()
+	}
+
+	#[inline]
+	fn try_push(&mut self, value: T) -> Result<(), T> {
+		self.push(value);
+		Ok(())
+	}
+
+	#[inline]
+	fn reusable_len(&self) -> usize {
+		self.reusable_len()
+	}
+
+	#[inline]
+	fn clear_reuse(&mut self) {
+		self.clear_reuse();
+	}
+
+	#[inline]
+	fn clear_drop(&mut self) {
+		self.clear_drop();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn it_should_spill_and_unspill() {
+		let mut lists = ReusableSmallVec::<Vec<u32>, 2>::new();
+
+		for i in 0..2 {
+			lists.push(Vec::with_capacity(10));
+			lists.last_mut().unwrap().push(i);
+		}
+
+		assert!(!lists.spilled());
+		lists.push(Vec::new());
+		assert!(lists.spilled());
+		assert_eq!(lists.as_slice(), [vec![0], vec![1], vec![]]);
+
+		lists.clear_reuse();
+		assert_eq!(lists.reusable_len(), 3);
+		assert_eq!(lists.push_reuse().unwrap().capacity(), 10);
+
+		lists.clear_drop();
+		assert!(lists.spilled());
+		lists.set_unspill_on_clear_drop(true);
+		lists.push(Vec::new());
+		lists.clear_drop();
+		assert!(!lists.spilled());
+		assert!(lists.push_reuse().is_none());
+	}
+}
@@ -0,0 +1,74 @@
+use crate::allocator::Allocator;
+use crate::ReusableVec;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReuseStats {
+	pub reuse_hits: u64,
+	pub reuse_misses: u64,
+	pub fresh_pushes: u64,
+	pub high_water_mark: usize,
+	pub clear_reuses: u64,
+	pub clear_drops: u64,
+}
+
+impl ReuseStats {
+	#[inline]
+	pub fn hit_rate(&self) -> f64 {
+		let attempts = self.reuse_hits + self.reuse_misses;
+
+		if attempts == 0 {
+			0.0
+		} else {
+			self.reuse_hits as f64 / attempts as f64
+		}
+	}
+
+	#[cfg(feature = "metrics")]
+	pub fn publish(&self, name: &str) {
+		let labels = [("name", alloc::string::String::from(name))];
+		metrics::counter!("reusable_vec_reuse_hits", &labels).absolute(self.reuse_hits);
+		metrics::counter!("reusable_vec_reuse_misses", &labels).absolute(self.reuse_misses);
+		metrics::counter!("reusable_vec_fresh_pushes", &labels).absolute(self.fresh_pushes);
+		metrics::gauge!("reusable_vec_high_water_mark", &labels).set(self.high_water_mark as f64);
+		metrics::counter!("reusable_vec_clear_reuses", &labels).absolute(self.clear_reuses);
+		metrics::counter!("reusable_vec_clear_drops", &labels).absolute(self.clear_drops);
+	}
+}
+
+impl<T, A: Allocator> ReusableVec<T, A> {
+	#[inline]
+	pub fn stats(&self) -> ReuseStats {
+		ReuseStats { high_water_mark: self.stats.high_water_mark.max(self.vec.len()), ..self.stats }
+	}
+
+	#[inline]
+	pub fn reset_stats(&mut self) {
+		self.stats = ReuseStats::default();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn it_should_count_reuse() {
+		let mut values = ReusableVec::new();
+		values.push(1);
+		values.push(2);
+		values.clear_reuse();
+		assert!(values.push_reuse().is_some());
+		values.push_with(|value| *value = 3, || 3);
+		assert!(values.push_reuse().is_none());
+		values.push_with(|value| *value = 4, || 4);
+		values.clear_drop();
+
+		let stats = values.stats();
+		assert_eq!((stats.reuse_hits, stats.reuse_misses, stats.fresh_pushes), (2, 2, 3));
+		assert_eq!((stats.high_water_mark, stats.clear_reuses, stats.clear_drops), (3, 1, 1));
+		assert_eq!(stats.hit_rate(), 0.5);
+
+		values.reset_stats();
+		assert_eq!(values.stats(), ReuseStats::default());
+	}
+}
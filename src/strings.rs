@@ -0,0 +1,276 @@
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::ops::{Deref, Index};
+use core::slice;
+
+use crate::ReusableVec;
+
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReusableString {
+	string: String,
+}
+
+impl ReusableString {
+	#[inline]
+	pub fn new() -> Self {
+		Self { string: String::new() }
+	}
+
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { string: String::with_capacity(capacity) }
+	}
+
+	#[inline]
+	pub fn set_str_reuse(&mut self, string: &str) -> &mut String {
+		self.string.clear();
+		self.string.push_str(string);
+		&mut self.string
+	}
+
+	#[inline]
+	pub fn set_fmt_reuse(&mut self, args: fmt::Arguments<'_>) -> &mut String {
+		self.string.clear();
+		self.string.write_fmt(args).expect("a formatting trait implementation returned an error");
+		&mut self.string
+	}
+
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.string
+	}
+
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.string.capacity()
+	}
+
+	#[inline]
+	pub fn clear_reuse(&mut self) {
+		self.string.clear();
+	}
+
+	#[inline]
+	pub fn clear_drop(&mut self) {
+		self.string = String::new();
+	}
+
+	#[inline]
+	pub fn into_string(self) -> String {
+		self.string
+	}
+}
+
+impl Deref for ReusableString {
+	type Target = str;
+
+	#[inline]
+	fn deref(&self) -> &str {
+		&self.string
+	}
+}
+
+impl fmt::Debug for ReusableString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.string, f)
+	}
+}
+
+impl fmt::Display for ReusableString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.string, f)
+	}
+}
+
+impl From<String> for ReusableString {
+	#[inline]
+	fn from(string: String) -> Self {
+		Self { string }
+	}
+}
+
+impl From<ReusableString> for String {
+	#[inline]
+	fn from(reusable: ReusableString) -> String {
+		reusable.string
+	}
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct ReusableStringVec {
+	strings: ReusableVec<String>,
+}
+
+impl ReusableStringVec {
+	#[inline]
+	pub fn new() -> Self {
+		Self { strings: ReusableVec::new() }
+	}
+
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { strings: ReusableVec::with_capacity(capacity) }
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.strings.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.strings.is_empty()
+	}
+
+	#[inline]
+	pub fn reusable_len(&self) -> usize {
+		self.strings.reusable_len()
+	}
+
+	#[inline]
+	pub fn push_cleared(&mut self) -> &mut String {
+		self.strings.push_with(String::clear, String::new)
+	}
+
+	#[inline]
+	pub fn push_str_reuse(&mut self, string: &str) -> &mut String {
+		let reused = self.push_cleared();
+		reused.push_str(string);
+		reused
+	}
+
+	#[inline]
+	pub fn push_fmt_reuse(&mut self, args: fmt::Arguments<'_>) -> &mut String {
+		let reused = self.push_cleared();
+		reused.write_fmt(args).expect("a formatting trait implementation returned an error");
+		reused
+	}
+
+	#[inline]
+	pub fn pop_reuse(&mut self) -> Option<&str> {
+		self.strings.pop_reuse().map(|string| string.as_str())
+	}
+
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<&str> {
+		self.strings.get(index).map(String::as_str)
+	}
+
+	#[inline]
+	pub fn iter(&self) -> Iter<'_> {
+		Iter(self.strings.iter())
+	}
+
+	#[inline]
+	pub fn as_slice(&self) -> &[String] {
+		self.strings.as_slice()
+	}
+
+	#[inline]
+	pub fn clear_reuse(&mut self) {
+		self.strings.clear_reuse();
+	}
+
+	#[inline]
+	pub fn clear_drop(&mut self) {
+		self.strings.clear_drop();
+	}
+}
+
+impl fmt::Debug for ReusableStringVec {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.strings, f)
+	}
+}
+
+impl Index<usize> for ReusableStringVec {
+	type Output = str;
+
+	#[inline]
+	fn index(&self, index: usize) -> &str {
+		&self.strings[index]
+	}
+}
+
+impl<'a> Extend<&'a str> for ReusableStringVec {
+	#[inline]
+	fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+		for string in iter {
+			self.push_str_reuse(string);
+		}
+	}
+}
+
+impl<'a> IntoIterator for &'a ReusableStringVec {
+	type Item = &'a str;
+	type IntoIter = Iter<'a>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct Iter<'a>(slice::Iter<'a, String>);
+
+impl<'a> Iterator for Iter<'a> {
+	type Item = &'a str;
+
+	#[inline]
+	fn next(&mut self) -> Option<&'a str> {
+		self.0.next().map(String::as_str)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(String::as_str)
+	}
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn it_should_copy_into_reused_strings() {
+		let mut strings = ReusableStringVec::new();
+		strings.push_str_reuse("a").reserve(100);
+		strings.push_fmt_reuse(format_args!("{}-{}", 1, 2));
+		assert_eq!(strings.iter().collect::<Vec<_>>(), ["a", "1-2"]);
+
+		strings.clear_reuse();
+		assert_eq!(strings.reusable_len(), 2);
+		assert!(strings.push_str_reuse("b").capacity() >= 100);
+		strings.extend(["c", "d"]);
+		assert_eq!(strings.iter().rev().collect::<Vec<_>>(), ["d", "c", "b"]);
+		assert_eq!(&strings[1], "c");
+		assert_eq!(strings.pop_reuse(), Some("d"));
+		assert_eq!(strings.push_cleared(), "");
+	}
+
+	#[test]
+	fn it_should_overwrite_the_reused_string() {
+		let mut string = ReusableString::with_capacity(100);
+		string.set_str_reuse("abc");
+		assert_eq!(string.set_fmt_reuse(format_args!("{}-{}", 1, 2)), "1-2");
+		assert!(string.starts_with("1-") && string.capacity() >= 100);
+		string.clear_reuse();
+		assert!(string.is_empty() && string.capacity() >= 100);
+		string.clear_drop();
+		assert_eq!(string.capacity(), 0);
+	}
+}